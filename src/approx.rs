@@ -0,0 +1,306 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+
+/// Tolerance-aware equality.
+///
+/// Vectors and matrices compare component-wise and are equal when every pair of components is.
+pub trait ApproxEq {
+    type Epsilon: Copy;
+
+    fn default_epsilon() -> Self::Epsilon;
+    fn default_max_relative() -> Self::Epsilon;
+    fn default_max_ulps() -> u32;
+
+    /// `|self - other| <= epsilon`.
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+    /// `|self - other| <= epsilon`, or `|self - other| <= max(|self|, |other|) * max_relative`.
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool;
+    /// `|self - other| <= epsilon`, or both values are at most `max_ulps` representable
+    /// values apart.
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+}
+
+macro_rules! impl_approx_eq_float {
+    ($t: ty, $bits: ty) => {
+        impl ApproxEq for $t {
+            type Epsilon = $t;
+
+            fn default_epsilon() -> $t {
+                <$t>::EPSILON
+            }
+
+            fn default_max_relative() -> $t {
+                <$t>::EPSILON
+            }
+
+            fn default_max_ulps() -> u32 {
+                4
+            }
+
+            fn abs_diff_eq(&self, other: &$t, epsilon: $t) -> bool {
+                self == other || (self - other).abs() <= epsilon
+            }
+
+            fn relative_eq(&self, other: &$t, epsilon: $t, max_relative: $t) -> bool {
+                if self == other {
+                    return true;
+                }
+                if self.is_infinite() || other.is_infinite() {
+                    return false;
+                }
+
+                let diff = (self - other).abs();
+                if diff <= epsilon {
+                    return true;
+                }
+                let largest = if self.abs() > other.abs() {
+                    self.abs()
+                } else {
+                    other.abs()
+                };
+                diff <= largest * max_relative
+            }
+
+            fn ulps_eq(&self, other: &$t, epsilon: $t, max_ulps: u32) -> bool {
+                if self.is_nan() || other.is_nan() {
+                    return false;
+                }
+                if self.abs_diff_eq(other, epsilon) {
+                    return true;
+                }
+                if self.is_sign_positive() != other.is_sign_positive() {
+                    return false;
+                }
+
+                let (a, b) = (self.to_bits() as $bits, other.to_bits() as $bits);
+                a.wrapping_sub(b).unsigned_abs() <= max_ulps.into()
+            }
+        }
+    };
+}
+
+impl_approx_eq_float!(f32, i32);
+impl_approx_eq_float!(f64, i64);
+
+impl<T, const N: usize> ApproxEq for Vector<T, N>
+where
+    T: ApproxEq,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+impl<T, const R: usize, const C: usize> ApproxEq for Matrix<T, R, C>
+where
+    T: ApproxEq,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.iter_elements()
+            .zip(other.iter_elements())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.iter_elements()
+            .zip(other.iter_elements())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.iter_elements()
+            .zip(other.iter_elements())
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+/// Parameters of `ApproxEq::abs_diff_eq`, used by `assert_abs_diff_eq!`.
+pub struct AbsDiff<T: ApproxEq> {
+    epsilon: T::Epsilon,
+}
+
+impl<T> Default for AbsDiff<T>
+where
+    T: ApproxEq,
+{
+    fn default() -> Self {
+        AbsDiff {
+            epsilon: T::default_epsilon(),
+        }
+    }
+}
+
+impl<T> AbsDiff<T>
+where
+    T: ApproxEq,
+{
+    pub fn epsilon(self, epsilon: T::Epsilon) -> Self {
+        AbsDiff { epsilon }
+    }
+
+    pub fn eq(&self, lhs: &T, rhs: &T) -> bool {
+        lhs.abs_diff_eq(rhs, self.epsilon)
+    }
+}
+
+/// Parameters of `ApproxEq::relative_eq`, used by `assert_relative_eq!`.
+pub struct Relative<T: ApproxEq> {
+    epsilon: T::Epsilon,
+    max_relative: T::Epsilon,
+}
+
+impl<T> Default for Relative<T>
+where
+    T: ApproxEq,
+{
+    fn default() -> Self {
+        Relative {
+            epsilon: T::default_epsilon(),
+            max_relative: T::default_max_relative(),
+        }
+    }
+}
+
+impl<T> Relative<T>
+where
+    T: ApproxEq,
+{
+    pub fn epsilon(self, epsilon: T::Epsilon) -> Self {
+        Relative { epsilon, ..self }
+    }
+
+    pub fn max_relative(self, max_relative: T::Epsilon) -> Self {
+        Relative {
+            max_relative,
+            ..self
+        }
+    }
+
+    pub fn eq(&self, lhs: &T, rhs: &T) -> bool {
+        lhs.relative_eq(rhs, self.epsilon, self.max_relative)
+    }
+}
+
+/// Parameters of `ApproxEq::ulps_eq`, used by `assert_ulps_eq!`.
+pub struct Ulps<T: ApproxEq> {
+    epsilon: T::Epsilon,
+    max_ulps: u32,
+}
+
+impl<T> Default for Ulps<T>
+where
+    T: ApproxEq,
+{
+    fn default() -> Self {
+        Ulps {
+            epsilon: T::default_epsilon(),
+            max_ulps: T::default_max_ulps(),
+        }
+    }
+}
+
+impl<T> Ulps<T>
+where
+    T: ApproxEq,
+{
+    pub fn epsilon(self, epsilon: T::Epsilon) -> Self {
+        Ulps { epsilon, ..self }
+    }
+
+    pub fn max_ulps(self, max_ulps: u32) -> Self {
+        Ulps { max_ulps, ..self }
+    }
+
+    pub fn eq(&self, lhs: &T, rhs: &T) -> bool {
+        lhs.ulps_eq(rhs, self.epsilon, self.max_ulps)
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_approx {
+    ($params: ident, $name: expr, $left: expr, $right: expr $(, $key: ident = $value: expr)*) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !$crate::approx::$params::default()$(.$key($value))*.eq(left, right) {
+                    panic!(
+                        "assertion failed: `{}(left, right)`\n  left: `{:?}`,\n right: `{:?}`",
+                        $name, left, right
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Asserts `ApproxEq::abs_diff_eq`, optionally taking `epsilon = ...`.
+#[macro_export]
+macro_rules! assert_abs_diff_eq {
+    ($left: expr, $right: expr $(, $key: ident = $value: expr)* $(,)?) => {
+        $crate::__assert_approx!(AbsDiff, "abs_diff_eq", $left, $right $(, $key = $value)*)
+    };
+}
+
+/// Asserts `ApproxEq::relative_eq`, optionally taking `epsilon = ...` and
+/// `max_relative = ...`.
+#[macro_export]
+macro_rules! assert_relative_eq {
+    ($left: expr, $right: expr $(, $key: ident = $value: expr)* $(,)?) => {
+        $crate::__assert_approx!(Relative, "relative_eq", $left, $right $(, $key = $value)*)
+    };
+}
+
+/// Asserts `ApproxEq::ulps_eq`, optionally taking `epsilon = ...` and `max_ulps = ...`.
+#[macro_export]
+macro_rules! assert_ulps_eq {
+    ($left: expr, $right: expr $(, $key: ident = $value: expr)* $(,)?) => {
+        $crate::__assert_approx!(Ulps, "ulps_eq", $left, $right $(, $key = $value)*)
+    };
+}
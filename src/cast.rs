@@ -0,0 +1,217 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use std::convert::TryFrom;
+
+/// Conversion between primitive scalar types.
+///
+/// - `cast` behaves like `as`: integers wrap, floats round and float-to-int saturates with
+///   `NaN` mapping to `0`.
+/// - `saturating_cast` clamps out-of-range values to the bounds of the target type.
+/// - `checked_cast` returns `None` for values the target type cannot hold, after truncating
+///   floats toward zero for integer targets.
+pub trait Cast<U>: Copy {
+    fn cast(self) -> U;
+    fn saturating_cast(self) -> U;
+    fn checked_cast(self) -> Option<U>;
+}
+
+macro_rules! impl_cast_int_to_int {
+    ($from: ty => $($to: ty),+) => {
+        $(
+            impl Cast<$to> for $from {
+                fn cast(self) -> $to {
+                    self as $to
+                }
+
+                fn saturating_cast(self) -> $to {
+                    let v = self as i128;
+                    if v < <$to>::MIN as i128 {
+                        <$to>::MIN
+                    } else if v > <$to>::MAX as i128 {
+                        <$to>::MAX
+                    } else {
+                        self as $to
+                    }
+                }
+
+                fn checked_cast(self) -> Option<$to> {
+                    <$to>::try_from(self).ok()
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_cast_int_to_float {
+    ($from: ty => $($to: ty),+) => {
+        $(
+            impl Cast<$to> for $from {
+                fn cast(self) -> $to {
+                    self as $to
+                }
+
+                fn saturating_cast(self) -> $to {
+                    self as $to
+                }
+
+                fn checked_cast(self) -> Option<$to> {
+                    Some(self as $to)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_cast_float_to_int {
+    ($from: ty => $($to: ty),+) => {
+        $(
+            impl Cast<$to> for $from {
+                fn cast(self) -> $to {
+                    self as $to
+                }
+
+                fn saturating_cast(self) -> $to {
+                    self as $to
+                }
+
+                fn checked_cast(self) -> Option<$to> {
+                    let min = <$to>::MIN as $from;
+                    let t = self.trunc();
+                    if t >= min && t < -min {
+                        Some(t as $to)
+                    } else {
+                        None
+                    }
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_cast_float_to_float {
+    ($from: ty => $($to: ty),+) => {
+        $(
+            impl Cast<$to> for $from {
+                fn cast(self) -> $to {
+                    self as $to
+                }
+
+                fn saturating_cast(self) -> $to {
+                    let v = self as $to;
+                    if v.is_infinite() && self.is_finite() {
+                        if v > 0.0 {
+                            <$to>::MAX
+                        } else {
+                            <$to>::MIN
+                        }
+                    } else {
+                        v
+                    }
+                }
+
+                fn checked_cast(self) -> Option<$to> {
+                    let v = self as $to;
+                    if v.is_infinite() && self.is_finite() {
+                        None
+                    } else {
+                        Some(v)
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_cast_int_to_int!(i8 => i8, i16, i32, i64);
+impl_cast_int_to_int!(i16 => i8, i16, i32, i64);
+impl_cast_int_to_int!(i32 => i8, i16, i32, i64);
+impl_cast_int_to_int!(i64 => i8, i16, i32, i64);
+impl_cast_int_to_float!(i8 => f32, f64);
+impl_cast_int_to_float!(i16 => f32, f64);
+impl_cast_int_to_float!(i32 => f32, f64);
+impl_cast_int_to_float!(i64 => f32, f64);
+impl_cast_float_to_int!(f32 => i8, i16, i32, i64);
+impl_cast_float_to_int!(f64 => i8, i16, i32, i64);
+impl_cast_float_to_float!(f32 => f32, f64);
+impl_cast_float_to_float!(f64 => f32, f64);
+
+impl<T, const N: usize> Vector<T, N> {
+    /// Converts every component with `as` semantics, like GLSL's `ivec3(vec3)`.
+    pub fn cast<U>(self) -> Vector<U, N>
+    where
+        T: Cast<U>,
+    {
+        self.map(Cast::cast)
+    }
+
+    pub fn saturating_cast<U>(self) -> Vector<U, N>
+    where
+        T: Cast<U>,
+    {
+        self.map(Cast::saturating_cast)
+    }
+
+    /// Returns `None` if any component is out of range for `U`.
+    pub fn checked_cast<U>(self) -> Option<Vector<U, N>>
+    where
+        T: Cast<U>,
+    {
+        let components = self.0.map(Cast::checked_cast);
+        if components.iter().any(Option::is_none) {
+            return None;
+        }
+        Some(Vector(components.map(Option::unwrap)))
+    }
+}
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Converts every component with `as` semantics, like GLSL's `dmat4(mat4)`.
+    pub fn cast<U>(self) -> Matrix<U, R, C>
+    where
+        T: Cast<U>,
+    {
+        self.map(Cast::cast)
+    }
+
+    pub fn saturating_cast<U>(self) -> Matrix<U, R, C>
+    where
+        T: Cast<U>,
+    {
+        self.map(Cast::saturating_cast)
+    }
+
+    /// Returns `None` if any component is out of range for `U`.
+    pub fn checked_cast<U>(self) -> Option<Matrix<U, R, C>>
+    where
+        T: Cast<U>,
+    {
+        let rows = self.0.map(|row| Vector(row).checked_cast());
+        if rows.iter().any(Option::is_none) {
+            return None;
+        }
+        Some(Matrix(rows.map(|row| row.unwrap().0)))
+    }
+}
+
+macro_rules! impl_from {
+    ($from: ty => $($to: ty),+) => {
+        $(
+            impl<const N: usize> From<Vector<$from, N>> for Vector<$to, N> {
+                fn from(v: Vector<$from, N>) -> Self {
+                    v.map(<$to>::from)
+                }
+            }
+
+            impl<const R: usize, const C: usize> From<Matrix<$from, R, C>> for Matrix<$to, R, C> {
+                fn from(m: Matrix<$from, R, C>) -> Self {
+                    m.map(<$to>::from)
+                }
+            }
+        )+
+    };
+}
+
+impl_from!(i8 => i16, i32, i64, f32, f64);
+impl_from!(i16 => i32, i64, f32, f64);
+impl_from!(i32 => i64, f64);
+impl_from!(f32 => f64);
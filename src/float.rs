@@ -1,17 +1,207 @@
+mod libm;
+pub mod soft;
+
 use crate::numeric::Numeric;
 
-pub trait Float: Numeric {
+pub(crate) use self::libm::Libm;
+
+/// Real scalar types.
+///
+/// A downstream type implements `Float` on top of [`Numeric`] to unlock lengths, normalization,
+/// inverses and the matrix functions. `PartialOrd` must order all non-NaN values, as it picks
+/// pivots and compares magnitudes. `EPSILON` is the gap between `1` and the next value and
+/// scales convergence tolerances. `f_sqrt` and `f_from_f64` round to nearest. Types without
+/// infinities or NaN use whatever `f_from_f64` gives for them, e.g. saturation for `INFINITY`.
+///
+/// The methods carry an `f_` prefix so they never shadow the inherent methods of `f32` and
+/// `f64`. The provided methods only need the arithmetic operators and can be overridden with
+/// more accurate versions.
+///
+/// `f_sqrt` is correctly rounded for the primitive floats, so it is bit-identical on every
+/// target. With the `deterministic` feature, the transcendental functions of the primitive
+/// floats and of the scalar types in [`crate::scalar`] come from [`soft`] instead of the
+/// platform `libm`.
+pub trait Float: Numeric + PartialOrd {
+    const PI: Self;
+    const EPSILON: Self;
+    const INFINITY: Self;
+    const NAN: Self;
+
+    fn f_is_nan(self) -> bool {
+        self.partial_cmp(&self).is_none()
+    }
+
+    fn f_is_finite(self) -> bool;
+    fn f_abs(self) -> Self;
+    /// `self` with the sign of `sign`.
+    fn f_copysign(self, sign: Self) -> Self;
+
+    /// The smaller value, ignoring a NaN operand.
+    fn f_min(self, other: Self) -> Self {
+        if self.f_is_nan() || other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// The larger value, ignoring a NaN operand.
+    fn f_max(self, other: Self) -> Self {
+        if self.f_is_nan() || other > self {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn f_floor(self) -> Self;
+    fn f_ceil(self) -> Self;
+
+    /// `self * a + b`, rounded once where the type supports it.
+    fn f_mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+
     fn f_sqrt(self) -> Self;
-}
+    fn f_exp(self) -> Self;
+    fn f_ln(self) -> Self;
+    fn f_sin(self) -> Self;
+    fn f_cos(self) -> Self;
 
-impl Float for f32 {
-    fn f_sqrt(self) -> f32 {
-        self.sqrt()
+    fn f_tan(self) -> Self {
+        self.f_sin() / self.f_cos()
     }
-}
 
-impl Float for f64 {
-    fn f_sqrt(self) -> f64 {
-        self.sqrt()
+    fn f_atan(self) -> Self {
+        self.f_atan2(Self::ONE)
+    }
+
+    /// The angle of the point `(x, self)`, in `[-π, π]`.
+    fn f_atan2(self, x: Self) -> Self;
+
+    fn f_powi(self, n: i32) -> Self {
+        let mut base = self;
+        let mut e = n.unsigned_abs();
+        let mut r = Self::ONE;
+        while e != 0 {
+            if e & 1 == 1 {
+                r = r * base;
+            }
+            base = base * base;
+            e >>= 1;
+        }
+        if n < 0 {
+            Self::ONE / r
+        } else {
+            r
+        }
+    }
+
+    fn f_powf(self, p: Self) -> Self {
+        (self.f_ln() * p).f_exp()
+    }
+
+    fn f_from_f64(v: f64) -> Self;
+
+    fn f_from_i32(v: i32) -> Self {
+        Self::f_from_f64(f64::from(v))
     }
 }
+
+macro_rules! impl_float {
+    ($t: ident) => {
+        impl Float for $t {
+            const PI: $t = std::$t::consts::PI;
+            const EPSILON: $t = $t::EPSILON;
+            const INFINITY: $t = $t::INFINITY;
+            const NAN: $t = $t::NAN;
+
+            fn f_is_nan(self) -> bool {
+                self.is_nan()
+            }
+
+            fn f_is_finite(self) -> bool {
+                self.is_finite()
+            }
+
+            fn f_abs(self) -> $t {
+                self.abs()
+            }
+
+            fn f_copysign(self, sign: $t) -> $t {
+                self.copysign(sign)
+            }
+
+            fn f_min(self, other: $t) -> $t {
+                self.min(other)
+            }
+
+            fn f_max(self, other: $t) -> $t {
+                self.max(other)
+            }
+
+            fn f_floor(self) -> $t {
+                self.floor()
+            }
+
+            fn f_ceil(self) -> $t {
+                self.ceil()
+            }
+
+            fn f_mul_add(self, a: $t, b: $t) -> $t {
+                self.mul_add(a, b)
+            }
+
+            fn f_sqrt(self) -> $t {
+                self.sqrt()
+            }
+
+            fn f_exp(self) -> $t {
+                self.l_exp()
+            }
+
+            fn f_ln(self) -> $t {
+                self.l_ln()
+            }
+
+            fn f_sin(self) -> $t {
+                self.l_sin()
+            }
+
+            fn f_cos(self) -> $t {
+                self.l_cos()
+            }
+
+            fn f_tan(self) -> $t {
+                self.l_tan()
+            }
+
+            fn f_atan(self) -> $t {
+                self.l_atan()
+            }
+
+            fn f_atan2(self, x: $t) -> $t {
+                self.l_atan2(x)
+            }
+
+            fn f_powi(self, n: i32) -> $t {
+                self.l_powi(n)
+            }
+
+            fn f_powf(self, p: $t) -> $t {
+                self.l_powf(p)
+            }
+
+            fn f_from_f64(v: f64) -> $t {
+                v as $t
+            }
+
+            fn f_from_i32(v: i32) -> $t {
+                v as $t
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
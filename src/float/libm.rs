@@ -0,0 +1,58 @@
+#[cfg(feature = "deterministic")]
+use crate::float::soft;
+
+/// The transcendental functions behind the crate's scalar types: `std` by default, [`soft`]
+/// with the `deterministic` feature.
+pub(crate) trait Libm: Copy {
+    fn l_exp(self) -> Self;
+    fn l_ln(self) -> Self;
+    fn l_sin(self) -> Self;
+    fn l_cos(self) -> Self;
+    fn l_tan(self) -> Self;
+    fn l_atan(self) -> Self;
+    fn l_atan2(self, x: Self) -> Self;
+    fn l_powf(self, p: Self) -> Self;
+    fn l_powi(self, n: i32) -> Self;
+}
+
+macro_rules! impl_libm {
+    ($t: ty, $($f: ident($($a: ident: $at: ty),*) => $std: ident / $soft: ident;)*) => {
+        impl Libm for $t {
+            $(
+                #[cfg(not(feature = "deterministic"))]
+                fn $f(self, $($a: $at),*) -> $t {
+                    self.$std($($a),*)
+                }
+
+                #[cfg(feature = "deterministic")]
+                fn $f(self, $($a: $at),*) -> $t {
+                    soft::$soft(self, $($a),*)
+                }
+            )*
+        }
+    };
+}
+
+impl_libm!(f32,
+    l_exp() => exp / expf;
+    l_ln() => ln / logf;
+    l_sin() => sin / sinf;
+    l_cos() => cos / cosf;
+    l_tan() => tan / tanf;
+    l_atan() => atan / atanf;
+    l_atan2(x: f32) => atan2 / atan2f;
+    l_powf(p: f32) => powf / powf;
+    l_powi(n: i32) => powi / powif;
+);
+
+impl_libm!(f64,
+    l_exp() => exp / exp;
+    l_ln() => ln / log;
+    l_sin() => sin / sin;
+    l_cos() => cos / cos;
+    l_tan() => tan / tan;
+    l_atan() => atan / atan;
+    l_atan2(x: f64) => atan2 / atan2;
+    l_powf(p: f64) => powf / pow;
+    l_powi(n: i32) => powi / powi;
+);
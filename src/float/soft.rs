@@ -0,0 +1,799 @@
+//! Software implementations of the transcendental functions.
+//!
+//! The algorithms follow fdlibm and use only IEEE 754 `+`, `-`, `*`, `/` and correctly rounded
+//! square roots, so every result is bit-identical on every target regardless of the platform
+//! `libm`. The `f64` functions are accurate to about one ulp; the `f32` variants evaluate the
+//! `f64` function and round the result. Trigonometric arguments of any magnitude are reduced
+//! exactly, by Cody–Waite below `2^20 · π/2` and Payne–Hanek above.
+
+// The coefficients are quoted with fdlibm's digits.
+#![allow(clippy::excessive_precision)]
+
+use std::f64::consts::{FRAC_2_PI, FRAC_PI_2, FRAC_PI_4, LN_2, LOG2_E, PI};
+
+const LN2_HI: f64 = 6.931_471_803_691_238_164_90e-1;
+const LN2_LO: f64 = 1.908_214_929_270_587_700_02e-10;
+const PI_LO: f64 = 1.224_646_799_147_353_177_2e-16;
+
+/// The high 32 bits of `x`, whose upper bits hold the sign and exponent.
+fn high_word(x: f64) -> u32 {
+    (x.to_bits() >> 32) as u32
+}
+
+/// `x` with the low 32 bits of its significand cleared, exact in products with few bits.
+fn high_part(x: f64) -> f64 {
+    f64::from_bits(x.to_bits() & 0xffff_ffff_0000_0000)
+}
+
+/// `x` with its high 32 bits replaced by `hi`.
+fn with_high_word(x: f64, hi: u32) -> f64 {
+    f64::from_bits(u64::from(hi) << 32 | (x.to_bits() & 0xffff_ffff))
+}
+
+/// `x * 2^n`, exact unless the result is subnormal.
+fn scalbn(mut x: f64, mut n: i32) -> f64 {
+    let p1023 = f64::from_bits(0x7fe0_0000_0000_0000);
+    let p_1022 = f64::from_bits(0x0010_0000_0000_0000);
+    if n > 1023 {
+        x *= p1023;
+        n -= 1023;
+        if n > 1023 {
+            x *= p1023;
+            n = (n - 1023).min(1023);
+        }
+    } else if n < -1022 {
+        // Scale in two steps, the second by 2^53 less, to avoid double rounding of subnormals.
+        x *= p_1022 * f64::from_bits(0x4340_0000_0000_0000);
+        n += 1022 - 53;
+        if n < -1022 {
+            x *= p_1022 * f64::from_bits(0x4340_0000_0000_0000);
+            n = (n + 1022 - 53).max(-1022);
+        }
+    }
+    x * f64::from_bits(((0x3ff + n) as u64) << 52)
+}
+
+pub fn exp(x: f64) -> f64 {
+    const P1: f64 = 1.666_666_666_666_660_190_37e-1;
+    const P2: f64 = -2.777_777_777_701_559_338_42e-3;
+    const P3: f64 = 6.613_756_321_437_934_361_17e-5;
+    const P4: f64 = -1.653_390_220_546_525_153_90e-6;
+    const P5: f64 = 4.138_136_797_057_238_460_39e-8;
+
+    if x.is_nan() {
+        return x;
+    }
+    if x > 709.782_712_893_383_973_096 {
+        return f64::INFINITY;
+    }
+    if x < -745.133_219_101_941_108_420 {
+        return 0.0;
+    }
+
+    // x = k * ln2 + r with |r| <= ln2 / 2.
+    let (k, hi, lo) = if x.abs() > 0.5 * LN2_HI {
+        let k = (x * LOG2_E).round_ties_even();
+        (k as i32, x - k * LN2_HI, k * LN2_LO)
+    } else if x.abs() < f64::from_bits(0x3e30_0000_0000_0000) {
+        return 1.0 + x;
+    } else {
+        (0, x, 0.0)
+    };
+    let r = hi - lo;
+    let t = r * r;
+    let c = r - t * (P1 + t * (P2 + t * (P3 + t * (P4 + t * P5))));
+    if k == 0 {
+        return 1.0 - ((r * c) / (c - 2.0) - r);
+    }
+    let y = 1.0 - ((lo - (r * c) / (2.0 - c)) - hi);
+    scalbn(y, k)
+}
+
+pub fn log(x: f64) -> f64 {
+    const LG1: f64 = 6.666_666_666_666_735_130e-1;
+    const LG2: f64 = 3.999_999_999_940_941_908e-1;
+    const LG3: f64 = 2.857_142_874_366_239_149e-1;
+    const LG4: f64 = 2.222_219_843_214_978_396e-1;
+    const LG5: f64 = 1.818_357_216_161_805_012e-1;
+    const LG6: f64 = 1.531_383_769_920_937_332e-1;
+    const LG7: f64 = 1.479_819_860_511_658_591e-1;
+
+    let mut x = x;
+    let mut hx = high_word(x);
+    let mut k: i32 = 0;
+    if hx < 0x0010_0000 || hx >> 31 != 0 {
+        if x == 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        if hx >> 31 != 0 {
+            return f64::NAN;
+        }
+        // Subnormal: scale into the normal range.
+        k -= 54;
+        x *= f64::from_bits(0x4350_0000_0000_0000);
+        hx = high_word(x);
+    } else if hx >= 0x7ff0_0000 {
+        return x;
+    } else if x == 1.0 {
+        return 0.0;
+    }
+
+    // Reduce x into [sqrt(2)/2, sqrt(2)).
+    hx += 0x3ff0_0000 - 0x3fe6_a09e;
+    k += (hx >> 20) as i32 - 0x3ff;
+    hx = (hx & 0x000f_ffff) + 0x3fe6_a09e;
+    x = f64::from_bits(u64::from(hx) << 32 | (x.to_bits() & 0xffff_ffff));
+
+    let f = x - 1.0;
+    let hfsq = 0.5 * f * f;
+    let s = f / (2.0 + f);
+    let z = s * s;
+    let w = z * z;
+    let t1 = w * (LG2 + w * (LG4 + w * LG6));
+    let t2 = z * (LG1 + w * (LG3 + w * (LG5 + w * LG7)));
+    let r = t2 + t1;
+    let dk = f64::from(k);
+    s * (hfsq + r) + dk * LN2_LO - hfsq + f + dk * LN2_HI
+}
+
+/// `sin(x + y)` for `|x + y| <= π/4`, where `y` is the tail of `x`.
+fn kernel_sin(x: f64, y: f64) -> f64 {
+    const S1: f64 = -1.666_666_666_666_663_243_48e-1;
+    const S2: f64 = 8.333_333_333_322_489_461_24e-3;
+    const S3: f64 = -1.984_126_982_985_794_931_34e-4;
+    const S4: f64 = 2.755_731_370_707_006_767_89e-6;
+    const S5: f64 = -2.505_076_025_340_686_341_95e-8;
+    const S6: f64 = 1.589_690_995_211_550_102_21e-10;
+
+    let z = x * x;
+    let w = z * z;
+    let r = S2 + z * (S3 + z * S4) + z * w * (S5 + z * S6);
+    let v = z * x;
+    x - ((z * (0.5 * y - v * r) - y) - v * S1)
+}
+
+/// `cos(x + y)` for `|x + y| <= π/4`, where `y` is the tail of `x`.
+fn kernel_cos(x: f64, y: f64) -> f64 {
+    const C1: f64 = 4.166_666_666_666_660_190_37e-2;
+    const C2: f64 = -1.388_888_888_887_410_957_49e-3;
+    const C3: f64 = 2.480_158_728_947_672_941_78e-5;
+    const C4: f64 = -2.755_731_435_139_066_330_35e-7;
+    const C5: f64 = 2.087_572_321_298_174_827_90e-9;
+    const C6: f64 = -1.135_964_755_778_819_482_65e-11;
+
+    let z = x * x;
+    let w = z * z;
+    let r = z * (C1 + z * (C2 + z * C3)) + w * w * (C4 + z * (C5 + z * C6));
+    let hz = 0.5 * z;
+    let w = 1.0 - hz;
+    w + (((1.0 - w) - hz) + (z * r - x * y))
+}
+
+/// `tan(x + y)` for `|x + y| <= π/4`, or `-1 / tan(x + y)` if `odd`, where `y` is the tail of
+/// `x`.
+fn kernel_tan(x: f64, y: f64, odd: bool) -> f64 {
+    const T: [f64; 13] = [
+        3.333_333_333_333_340_919_86e-1,
+        1.333_333_333_332_012_426_99e-1,
+        5.396_825_397_622_605_213_77e-2,
+        2.186_948_829_485_954_245_99e-2,
+        8.863_239_823_599_300_057_37e-3,
+        3.592_079_107_591_312_353_56e-3,
+        1.456_209_454_325_290_255_16e-3,
+        5.880_412_408_202_640_968_74e-4,
+        2.464_631_348_184_699_068_12e-4,
+        7.817_944_429_395_570_923_00e-5,
+        7.140_724_913_826_081_903_05e-5,
+        -1.855_863_748_552_754_566_54e-5,
+        2.590_730_518_636_337_128_84e-5,
+    ];
+    const PIO4_LO: f64 = 3.061_616_997_868_383_017_93e-17;
+
+    // Above 0.6744, tan(π/4 - x) converges faster.
+    let big = high_word(x) & 0x7fff_ffff >= 0x3fe5_9428;
+    let negative = x.is_sign_negative();
+    let (x, y) = if big {
+        let (x, y) = if negative { (-x, -y) } else { (x, y) };
+        ((FRAC_PI_4 - x) + (PIO4_LO - y), 0.0)
+    } else {
+        (x, y)
+    };
+    let z = x * x;
+    let w = z * z;
+    let r = T[1] + w * (T[3] + w * (T[5] + w * (T[7] + w * (T[9] + w * T[11]))));
+    let v = z * (T[2] + w * (T[4] + w * (T[6] + w * (T[8] + w * (T[10] + w * T[12])))));
+    let s = z * x;
+    let r = y + z * (s * (r + v) + y) + s * T[0];
+    let w = x + r;
+    if big {
+        let s = if odd { -1.0 } else { 1.0 };
+        let v = s - 2.0 * (x + (r - w * w / (w + s)));
+        return if negative { -v } else { v };
+    }
+    if !odd {
+        return w;
+    }
+    // -1 / w has up to two ulps of error, so divide with the high part split off.
+    let w0 = high_part(w);
+    let v = r - (w0 - x);
+    let a = -1.0 / w;
+    let a0 = high_part(a);
+    a0 + a * (1.0 + a0 * w0 + a0 * v)
+}
+
+/// The first 1280 fractional bits of `2/π`.
+const TWO_OVER_PI: [u64; 20] = [
+    0xa2f9_836e_4e44_1529,
+    0xfc27_57d1_f534_ddc0,
+    0xdb62_9599_3c43_9041,
+    0xfe51_63ab_debb_c561,
+    0xb724_6e3a_424d_d2e0,
+    0x0649_2eea_09d1_921c,
+    0xfe1d_eb1c_b129_a73e,
+    0xe882_35f5_2ebb_4484,
+    0xe99c_7026_b45f_7e41,
+    0x3991_d639_8353_39f4,
+    0x9c84_5f8b_bdf9_283b,
+    0x1ff8_97ff_de05_980f,
+    0xef2f_118b_5a0a_6d1f,
+    0x6d36_7ecf_27cb_09b7,
+    0x4f46_3f66_9e5f_ea2d,
+    0x7527_bac7_ebe5_f17b,
+    0x3d07_39f7_8a52_92ea,
+    0x6bfb_5fb1_1f8d_5d08,
+    0x5603_3046_fc7b_6bab,
+    0xf0cf_bc20_9af4_361d,
+];
+
+/// The 64 bits of `2/π` starting at fractional bit `j` (1-based); bits at `j <= 0` are zero.
+fn two_over_pi_bits(j: i32) -> u64 {
+    if j <= -63 {
+        return 0;
+    }
+    if j <= 0 {
+        return TWO_OVER_PI[0] >> (1 - j);
+    }
+    let (i, sh) = ((j - 1) as usize / 64, (j - 1) as u32 % 64);
+    if sh == 0 {
+        TWO_OVER_PI[i]
+    } else {
+        TWO_OVER_PI[i] << sh | TWO_OVER_PI[i + 1] >> (64 - sh)
+    }
+}
+
+/// `a * b` as an unevaluated sum, by Dekker's splitting.
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let split = |v: f64| {
+        let t = 134_217_729.0 * v;
+        let hi = t - (t - v);
+        (hi, v - hi)
+    };
+    let p = a * b;
+    let ((ah, al), (bh, bl)) = (split(a), split(b));
+    (p, ((ah * bh - p) + ah * bl + al * bh) + al * bl)
+}
+
+/// Payne–Hanek reduction of a finite `|x| >= 2^20 · π/2`: multiplies the 53-bit significand
+/// by a 192-bit window of `2/π`, so the result is exact to well beyond `f64` precision.
+fn rem_pio2_large(x: f64) -> (i32, f64, f64) {
+    const PIO2_LO: f64 = 6.123_233_995_736_766e-17;
+
+    let bits = x.to_bits();
+    let m = bits & 0x000f_ffff_ffff_ffff | 0x0010_0000_0000_0000;
+    let e = ((bits >> 52) & 0x7ff) as i32 - 1075;
+
+    // Bits of 2/π before `e - 1` contribute multiples of 4 to `|x| · 2/π` and are skipped;
+    // the window is then the fixed point number `m · w · 2^-190 mod 4`.
+    let w = [e - 1, e + 63, e + 127].map(two_over_pi_bits);
+    let p2 = u128::from(m) * u128::from(w[2]);
+    let p1 = u128::from(m) * u128::from(w[1]) + (p2 >> 64);
+    let p0 = u128::from(m) * u128::from(w[0]) + (p1 >> 64);
+    let (t0, t1, t2) = (p0 as u64, p1 as u64, p2 as u64);
+
+    // Round to the nearest quadrant, leaving a signed fraction in `[-1/2, 1/2)` scaled by 2^128.
+    let frac = u128::from(t0) << 66 | u128::from(t1) << 2 | u128::from(t2 >> 62);
+    let n = (t0 >> 62) as i32 + (frac >> 127) as i32;
+    let f = frac as i128;
+    let hi = f as f64;
+    let lo = (f - hi as i128) as f64;
+    let scale = f64::from_bits(0x37f0_0000_0000_0000);
+    let (a, b) = (hi * scale, lo * scale);
+
+    let (p, e) = two_prod(a, FRAC_PI_2);
+    let e = e + (a * PIO2_LO + b * FRAC_PI_2);
+    let y0 = p + e;
+    let y1 = e - (y0 - p);
+    if x < 0.0 {
+        (-n & 3, -y0, -y1)
+    } else {
+        (n & 3, y0, y1)
+    }
+}
+
+/// Reduces `x` to `y0 + y1` in `[-π/4, π/4]` and returns the quadrant.
+fn rem_pio2(x: f64) -> (i32, f64, f64) {
+    const PIO2_1: f64 = 1.570_796_326_734_125_614_17;
+    const PIO2_1T: f64 = 6.077_100_506_506_192_249_32e-11;
+    const PIO2_2: f64 = 6.077_100_506_303_965_976_60e-11;
+    const PIO2_2T: f64 = 2.022_266_248_795_950_631_54e-21;
+    const PIO2_3: f64 = 2.022_266_248_711_166_455_80e-21;
+    const PIO2_3T: f64 = 8.478_427_660_368_899_569_97e-32;
+
+    if high_word(x) & 0x7fff_ffff >= 0x4139_21fb {
+        return rem_pio2_large(x);
+    }
+    let exponent = |v: f64| ((v.to_bits() >> 52) & 0x7ff) as i32;
+    let n = (x * FRAC_2_PI).round_ties_even();
+    let mut r = x - n * PIO2_1;
+    let mut w = n * PIO2_1T;
+    let mut y0 = r - w;
+    if exponent(x) - exponent(y0) > 16 {
+        // Cancellation: subtract the next 33 bits of π/2.
+        let t = r;
+        w = n * PIO2_2;
+        r = t - w;
+        w = n * PIO2_2T - ((t - r) - w);
+        y0 = r - w;
+        if exponent(x) - exponent(y0) > 49 {
+            let t = r;
+            w = n * PIO2_3;
+            r = t - w;
+            w = n * PIO2_3T - ((t - r) - w);
+            y0 = r - w;
+        }
+    }
+    let y1 = (r - y0) - w;
+    ((n % 4.0) as i32 & 3, y0, y1)
+}
+
+pub fn sin(x: f64) -> f64 {
+    if high_word(x) & 0x7fff_ffff <= 0x3fe9_21fb {
+        if x.abs() < f64::from_bits(0x3e50_0000_0000_0000) {
+            return x;
+        }
+        return kernel_sin(x, 0.0);
+    }
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    match rem_pio2(x) {
+        (0, y0, y1) => kernel_sin(y0, y1),
+        (1, y0, y1) => kernel_cos(y0, y1),
+        (2, y0, y1) => -kernel_sin(y0, y1),
+        (_, y0, y1) => -kernel_cos(y0, y1),
+    }
+}
+
+pub fn cos(x: f64) -> f64 {
+    if high_word(x) & 0x7fff_ffff <= 0x3fe9_21fb {
+        if x.abs() < f64::from_bits(0x3e46_a09e_667f_3bcd) {
+            return 1.0;
+        }
+        return kernel_cos(x, 0.0);
+    }
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    match rem_pio2(x) {
+        (0, y0, y1) => kernel_cos(y0, y1),
+        (1, y0, y1) => -kernel_sin(y0, y1),
+        (2, y0, y1) => -kernel_cos(y0, y1),
+        (_, y0, y1) => kernel_sin(y0, y1),
+    }
+}
+
+pub fn tan(x: f64) -> f64 {
+    if high_word(x) & 0x7fff_ffff <= 0x3fe9_21fb {
+        if x.abs() < f64::from_bits(0x3e40_0000_0000_0000) {
+            return x;
+        }
+        return kernel_tan(x, 0.0, false);
+    }
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    let (n, y0, y1) = rem_pio2(x);
+    kernel_tan(y0, y1, n & 1 == 1)
+}
+
+pub fn atan(x: f64) -> f64 {
+    const ATAN_HI: [f64; 4] = [
+        4.636_476_090_008_060_935_15e-1,
+        FRAC_PI_4,
+        9.827_937_232_473_290_540_82e-1,
+        FRAC_PI_2,
+    ];
+    const ATAN_LO: [f64; 4] = [
+        2.269_877_745_296_168_709_24e-17,
+        3.061_616_997_868_383_017_93e-17,
+        1.390_331_103_123_099_845_16e-17,
+        6.123_233_995_736_766_035_87e-17,
+    ];
+    const AT: [f64; 11] = [
+        3.333_333_333_333_293_180_27e-1,
+        -1.999_999_999_987_648_324_76e-1,
+        1.428_571_427_250_346_637_11e-1,
+        -1.111_111_040_546_235_578_80e-1,
+        9.090_887_133_436_506_561_96e-2,
+        -7.691_876_205_044_829_994_95e-2,
+        6.661_073_137_387_531_206_69e-2,
+        -5.833_570_133_790_573_486_45e-2,
+        4.976_877_994_615_932_360_17e-2,
+        -3.653_157_274_421_691_552_70e-2,
+        1.628_582_011_536_578_236_23e-2,
+    ];
+
+    let ix = high_word(x) & 0x7fff_ffff;
+    let negative = x.is_sign_negative();
+    if ix >= 0x4410_0000 {
+        if x.is_nan() {
+            return x;
+        }
+        let z = ATAN_HI[3] + ATAN_LO[3];
+        return if negative { -z } else { z };
+    }
+    let (id, x) = if ix < 0x3fdc_0000 {
+        if ix < 0x3e40_0000 {
+            return x;
+        }
+        (None, x)
+    } else {
+        let x = x.abs();
+        if ix < 0x3ff3_0000 {
+            if ix < 0x3fe6_0000 {
+                (Some(0), (2.0 * x - 1.0) / (2.0 + x))
+            } else {
+                (Some(1), (x - 1.0) / (x + 1.0))
+            }
+        } else if ix < 0x4003_8000 {
+            (Some(2), (x - 1.5) / (1.0 + 1.5 * x))
+        } else {
+            (Some(3), -1.0 / x)
+        }
+    };
+
+    let z = x * x;
+    let w = z * z;
+    let s1 = z * (AT[0] + w * (AT[2] + w * (AT[4] + w * (AT[6] + w * (AT[8] + w * AT[10])))));
+    let s2 = w * (AT[1] + w * (AT[3] + w * (AT[5] + w * (AT[7] + w * AT[9]))));
+    match id {
+        None => x - x * (s1 + s2),
+        Some(id) => {
+            let z = ATAN_HI[id] - ((x * (s1 + s2) - ATAN_LO[id]) - x);
+            if negative {
+                -z
+            } else {
+                z
+            }
+        }
+    }
+}
+
+pub fn atan2(y: f64, x: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+    if x == 1.0 {
+        return atan(y);
+    }
+    let m = (y.is_sign_negative() as u8) | (x.is_sign_negative() as u8) << 1;
+    if y == 0.0 {
+        return match m {
+            0 | 1 => y,
+            2 => PI,
+            _ => -PI,
+        };
+    }
+    if x == 0.0 {
+        return if m & 1 == 0 { FRAC_PI_2 } else { -FRAC_PI_2 };
+    }
+    if x.is_infinite() {
+        let z = if y.is_infinite() {
+            if m & 2 == 0 {
+                FRAC_PI_4
+            } else {
+                3.0 * FRAC_PI_4
+            }
+        } else if m & 2 == 0 {
+            0.0
+        } else {
+            PI
+        };
+        return if m & 1 == 0 { z } else { -z };
+    }
+    if y.is_infinite() {
+        return if m & 1 == 0 { FRAC_PI_2 } else { -FRAC_PI_2 };
+    }
+
+    let exponent = |v: f64| ((v.to_bits() >> 52) & 0x7ff) as i32;
+    let (z, m) = if exponent(y) - exponent(x) > 60 {
+        (FRAC_PI_2 + 0.5 * PI_LO, m & 1)
+    } else if m & 2 != 0 && exponent(x) - exponent(y) > 60 {
+        (0.0, m)
+    } else {
+        (atan((y / x).abs()), m)
+    };
+    match m {
+        0 => z,
+        1 => -z,
+        2 => PI - (z - PI_LO),
+        _ => (z - PI_LO) - PI,
+    }
+}
+
+/// `x^y` with the IEEE 754 special cases, after fdlibm's `e_pow.c`.
+///
+/// `log2(x)` is evaluated as an unevaluated sum with about 20 extra bits, so the result stays
+/// within one ulp even where `|y * log2(x)|` is large.
+pub fn pow(x: f64, y: f64) -> f64 {
+    const BP: [f64; 2] = [1.0, 1.5];
+    const DP_H: [f64; 2] = [0.0, 5.849_624_872_207_641_601_56e-1];
+    const DP_L: [f64; 2] = [0.0, 1.350_039_202_129_748_971_28e-8];
+    const L1: f64 = 5.999_999_999_999_946_487_25e-1;
+    const L2: f64 = 4.285_714_285_785_501_842_52e-1;
+    const L3: f64 = 3.333_333_298_183_774_329_18e-1;
+    const L4: f64 = 2.727_281_238_085_340_064_89e-1;
+    const L5: f64 = 2.306_607_457_755_617_540_67e-1;
+    const L6: f64 = 2.069_750_178_003_384_177_84e-1;
+    const P1: f64 = 1.666_666_666_666_660_190_37e-1;
+    const P2: f64 = -2.777_777_777_701_559_338_42e-3;
+    const P3: f64 = 6.613_756_321_437_934_361_17e-5;
+    const P4: f64 = -1.653_390_220_546_525_153_90e-6;
+    const P5: f64 = 4.138_136_797_057_238_460_39e-8;
+    const LG2_H: f64 = 6.931_471_824_645_996_093_75e-1;
+    const LG2_L: f64 = -1.904_654_299_957_768_045_25e-9;
+    // -(1024 - log2(overflow threshold + 0.5 ulp)).
+    const OVT: f64 = 8.008_566_259_537_294_437_2e-17;
+    // 2 / (3 ln 2), and its leading 24 bits plus the tail.
+    const CP: f64 = 9.617_966_939_259_755_543_29e-1;
+    const CP_H: f64 = 9.617_967_009_544_372_558_59e-1;
+    const CP_L: f64 = -7.028_461_650_952_758_265_16e-9;
+    // The leading 21 bits of 1 / ln 2, plus the tail.
+    const IVLN2_H: f64 = 1.442_695_021_629_333_496_09;
+    const IVLN2_L: f64 = 1.925_962_991_126_617_468_87e-8;
+
+    let (hx, lx) = (high_word(x) as i32, x.to_bits() as u32);
+    let (hy, ly) = (high_word(y) as i32, y.to_bits() as u32);
+    let mut ix = hx & 0x7fff_ffff;
+    let iy = hy & 0x7fff_ffff;
+
+    // x^0 = 1 and 1^y = 1, even for NaN.
+    if iy as u32 | ly == 0 || (hx == 0x3ff0_0000 && lx == 0) {
+        return 1.0;
+    }
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+
+    // For x < 0: 0 if y is not an integer, 1 if it is odd, 2 if it is even.
+    let mut yisint = 0;
+    if hx < 0 {
+        if iy >= 0x4340_0000 {
+            yisint = 2;
+        } else if iy >= 0x3ff0_0000 {
+            let k = (iy >> 20) - 0x3ff;
+            if k > 20 {
+                let j = ly >> (52 - k);
+                if j << (52 - k) == ly {
+                    yisint = 2 - (j & 1) as i32;
+                }
+            } else if ly == 0 {
+                let j = iy >> (20 - k);
+                if j << (20 - k) == iy {
+                    yisint = 2 - (j & 1);
+                }
+            }
+        }
+    }
+
+    if ly == 0 {
+        if iy == 0x7ff0_0000 {
+            // y = ±inf.
+            return if (ix - 0x3ff0_0000) as u32 | lx == 0 {
+                1.0
+            } else if ix >= 0x3ff0_0000 {
+                if hy >= 0 {
+                    y
+                } else {
+                    0.0
+                }
+            } else if hy >= 0 {
+                0.0
+            } else {
+                -y
+            };
+        }
+        if iy == 0x3ff0_0000 {
+            return if hy >= 0 { x } else { 1.0 / x };
+        }
+        if hy == 0x4000_0000 {
+            return x * x;
+        }
+        if hy == 0x3fe0_0000 && hx >= 0 {
+            return x.sqrt();
+        }
+    }
+
+    let mut ax = x.abs();
+    if lx == 0 && (ix == 0x7ff0_0000 || ix == 0 || ix == 0x3ff0_0000) {
+        // x = ±0, ±inf or ±1.
+        let mut z = if hy < 0 { 1.0 / ax } else { ax };
+        if hx < 0 {
+            if ix == 0x3ff0_0000 && yisint == 0 {
+                z = f64::NAN;
+            } else if yisint == 1 {
+                z = -z;
+            }
+        }
+        return z;
+    }
+
+    let mut s = 1.0;
+    if hx < 0 {
+        match yisint {
+            0 => return f64::NAN,
+            1 => s = -1.0,
+            _ => {}
+        }
+    }
+
+    // log2(|x|) = t1 + t2, where t1 has its low word cleared.
+    let (t1, t2) = if iy > 0x41e0_0000 {
+        // |y| > 2^31: the result over- or underflows unless x is within 2^-20 of one.
+        if iy > 0x43f0_0000 {
+            if ix <= 0x3fef_ffff {
+                return if hy < 0 { f64::INFINITY } else { 0.0 };
+            }
+            if ix >= 0x3ff0_0000 {
+                return if hy > 0 { f64::INFINITY } else { 0.0 };
+            }
+        }
+        if ix < 0x3fef_ffff {
+            return if hy < 0 { s * f64::INFINITY } else { s * 0.0 };
+        }
+        if ix > 0x3ff0_0000 {
+            return if hy > 0 { s * f64::INFINITY } else { s * 0.0 };
+        }
+        // log(x) by x - x^2/2 + x^3/3 - x^4/4, with t = x - 1 exact.
+        let t = ax - 1.0;
+        let w = (t * t) * (0.5 - t * (0.333_333_333_333_333_333_333_3 - t * 0.25));
+        let u = IVLN2_H * t;
+        let v = t * IVLN2_L - w * LOG2_E;
+        let t1 = high_part(u + v);
+        (t1, v - (t1 - u))
+    } else {
+        let mut n = 0;
+        if ix < 0x0010_0000 {
+            // Subnormal: scale into the normal range.
+            ax *= f64::from_bits(0x4340_0000_0000_0000);
+            n -= 53;
+            ix = high_word(ax) as i32;
+        }
+        n += (ix >> 20) - 0x3ff;
+        let j = ix & 0x000f_ffff;
+        ix = j | 0x3ff0_0000;
+        // Reduce the significand to [1, sqrt(3/2)) or [sqrt(3/2), sqrt(3)), around 1 or 1.5.
+        let k = if j <= 0x3_988e {
+            0
+        } else if j < 0xb_b67a {
+            1
+        } else {
+            n += 1;
+            ix -= 0x0010_0000;
+            0
+        };
+        ax = with_high_word(ax, ix as u32);
+
+        // ss = s_h + s_l = (x - bp) / (x + bp).
+        let u = ax - BP[k];
+        let v = 1.0 / (ax + BP[k]);
+        let ss = u * v;
+        let s_h = high_part(ss);
+        // The high part of x + bp.
+        let t_h = f64::from_bits(
+            u64::from(((ix >> 1) as u32 | 0x2000_0000) + 0x0008_0000 + ((k as u32) << 18)) << 32,
+        );
+        let t_l = ax - (t_h - BP[k]);
+        let s_l = v * ((u - s_h * t_h) - s_h * t_l);
+
+        // log(x) = 2 ss + 2/3 ss^3 + r.
+        let s2 = ss * ss;
+        let mut r = s2 * s2 * (L1 + s2 * (L2 + s2 * (L3 + s2 * (L4 + s2 * (L5 + s2 * L6)))));
+        r += s_l * (s_h + ss);
+        let s2 = s_h * s_h;
+        let t_h = high_part(3.0 + s2 + r);
+        let t_l = r - ((t_h - 3.0) - s2);
+        let u = s_h * t_h;
+        let v = s_l * t_h + t_l * ss;
+        // 2 / (3 ln 2) * (ss + ...).
+        let p_h = high_part(u + v);
+        let p_l = v - (p_h - u);
+        let z_h = CP_H * p_h;
+        let z_l = CP_L * p_h + p_l * CP + DP_L[k];
+        // log2(x) = n + dp_h + z_h + z_l.
+        let t = f64::from(n);
+        let t1 = high_part(((z_h + z_l) + DP_H[k]) + t);
+        (t1, z_l - (((t1 - t) - DP_H[k]) - z_h))
+    };
+
+    // y * log2(x) = p_h + p_l, with y split into y1 + (y - y1).
+    let y1 = high_part(y);
+    let p_l = (y - y1) * t1 + y * t2;
+    let mut p_h = y1 * t1;
+    let z = p_l + p_h;
+    let j = high_word(z) as i32;
+    let i = z.to_bits() as u32;
+    if j >= 0x4090_0000 {
+        // z >= 1024.
+        if (j - 0x4090_0000) as u32 | i != 0 || p_l + OVT > z - p_h {
+            return s * f64::INFINITY;
+        }
+    } else if j & 0x7fff_ffff >= 0x4090_cc00 {
+        // z <= -1075.
+        if (j as u32).wrapping_sub(0xc090_cc00) | i != 0 || p_l <= z - p_h {
+            return s * 0.0;
+        }
+    }
+
+    // 2^(p_h + p_l) = 2^n * 2^r with |r| <= 0.5.
+    let i = j & 0x7fff_ffff;
+    let mut k = (i >> 20) - 0x3ff;
+    let mut n = 0;
+    if i > 0x3fe0_0000 {
+        n = j + (0x0010_0000 >> (k + 1));
+        k = ((n & 0x7fff_ffff) >> 20) - 0x3ff;
+        let t = f64::from_bits(u64::from((n & !(0x000f_ffff >> k)) as u32) << 32);
+        n = ((n & 0x000f_ffff) | 0x0010_0000) >> (20 - k);
+        if j < 0 {
+            n = -n;
+        }
+        p_h -= t;
+    }
+    let t = high_part(p_l + p_h);
+    let u = t * LG2_H;
+    let v = (p_l - (t - p_h)) * LN_2 + t * LG2_L;
+    let z = u + v;
+    let w = v - (z - u);
+    let t = z * z;
+    let t1 = z - t * (P1 + t * (P2 + t * (P3 + t * (P4 + t * P5))));
+    let r = (z * t1) / (t1 - 2.0) - (w + z * w);
+    let z = 1.0 - (r - z);
+    let j = high_word(z) as i32 + (n << 20);
+    let z = if j >> 20 <= 0 {
+        scalbn(z, n)
+    } else {
+        with_high_word(z, j as u32)
+    };
+    s * z
+}
+
+/// `x^n`, evaluated as [`pow`] rather than by repeated multiplication, which rounds at every
+/// step.
+pub fn powi(x: f64, n: i32) -> f64 {
+    pow(x, f64::from(n))
+}
+
+macro_rules! impl_f32 {
+    ($($f32: ident => $f64: ident($($a: ident),*);)*) => {
+        $(
+            pub fn $f32($($a: f32),*) -> f32 {
+                $f64($(f64::from($a)),*) as f32
+            }
+        )*
+    };
+}
+
+impl_f32! {
+    expf => exp(x);
+    logf => log(x);
+    sinf => sin(x);
+    cosf => cos(x);
+    tanf => tan(x);
+    atanf => atan(x);
+    atan2f => atan2(y, x);
+    powf => pow(x, y);
+}
+
+pub fn powif(x: f32, n: i32) -> f32 {
+    powi(f64::from(x), n) as f32
+}
@@ -1,9 +1,15 @@
+pub mod approx;
+pub mod cast;
 pub mod float;
 #[macro_use]
 pub mod macros;
-pub mod matrix;
+#[macro_use]
+mod ops;
+#[macro_use]
 pub mod numeric;
+pub mod matrix;
+pub mod scalar;
 pub mod vector;
 
-pub use self::vector::{V2, V3, V4};
-pub use self::matrix::{M2, M3, M4};
+pub use self::vector::{Vector, V2, V3, V4};
+pub use self::matrix::{Matrix, M2, M3, M4};
@@ -1,61 +1,112 @@
+pub mod arity;
 pub mod converter;
 pub mod finalizer;
+pub mod glsl;
+pub mod promote;
 pub mod types;
 
+#[doc(hidden)]
 #[macro_export]
-macro_rules! vec2 {
-    ($($e: expr),+) => {
+macro_rules! __vec {
+    ($n: expr, $e: expr) => {
         {
             use $crate::macros::types;
             use $crate::macros::converter::Converter;
             use $crate::macros::finalizer::Finalizer;
 
+            let e = types::EmptyConverter.convert($e);
+            Finalizer::<$crate::vector::Vector<_, $n>>::finalize(e)
+        }
+    };
+    ($n: expr, $($e: expr),+) => {
+        {
+            use $crate::macros::arity::Arity;
+            use $crate::macros::types;
+            use $crate::macros::converter::Converter;
+
             let e = types::EmptyConverter;
             $(
-                let e = e.convert($e);
+                let e = e.expect_components::<$n>().room().convert($e);
             )+
-            let f: $crate::vector::V2<_> = e.finalize();
-            f
+            e.expect_components::<$n>().complete()
         }
-    }
+    };
 }
 
+#[doc(hidden)]
 #[macro_export]
-macro_rules! vec3 { ($($e: expr), +) => {
+macro_rules! __row {
+    ($n: expr, $row: expr, $($e: expr),+) => {
         {
+            use $crate::macros::arity::Arity;
             use $crate::macros::types;
             use $crate::macros::converter::Converter;
-            use $crate::macros::finalizer::Finalizer;
 
             let e = types::EmptyConverter;
             $(
-                let e = e.convert($e);
+                let e = e.convert($e).expect_row::<$n, $row>().fits();
             )+
-            let f: $crate::vector::V3<_> = e.finalize();
-            f
+            e.expect_row::<$n, $row>().complete()
         }
-    }
+    };
 }
 
+/// Builds a `V2` from scalars and vectors, promoting mixed component types.
+///
+/// `vec2!(const ...)` takes exactly 2 scalars of one type, or a single scalar to splat, and
+/// expands to an array literal that can initialize a `const`.
 #[macro_export]
-macro_rules! vec4 {
-    ($($e: expr), +) => {
-        {
-            use $crate::macros::types; use $crate::macros::converter::Converter;
-            use $crate::macros::finalizer::Finalizer;
+macro_rules! vec2 {
+    (const $e: expr) => {
+        $crate::vector::V2([$e; 2])
+    };
+    (const $($e: expr),+) => {
+        $crate::vector::V2([$($e),+])
+    };
+    ($($e: expr),+) => {
+        $crate::__vec!(2, $($e),+)
+    };
+}
 
-            let e = types::EmptyConverter;
-            $(
-                let e = e.convert($e);
-            )+
-            let f: $crate::vector::V4<_> = e.finalize();
-            f
-        }
-    }
+/// Builds a `V3`, see `vec2!` for the `const` form.
+#[macro_export]
+macro_rules! vec3 {
+    (const $e: expr) => {
+        $crate::vector::V3([$e; 3])
+    };
+    (const $($e: expr),+) => {
+        $crate::vector::V3([$($e),+])
+    };
+    ($($e: expr),+) => {
+        $crate::__vec!(3, $($e),+)
+    };
 }
 
+/// Builds a `V4`, see `vec2!` for the `const` form.
+#[macro_export]
+macro_rules! vec4 {
+    (const $e: expr) => {
+        $crate::vector::V4([$e; 4])
+    };
+    (const $($e: expr),+) => {
+        $crate::vector::V4([$($e),+])
+    };
+    ($($e: expr),+) => {
+        $crate::__vec!(4, $($e),+)
+    };
+}
+
+/// Builds an `M2` from a scalar to splat, 4 scalars in row-major order, or rows separated by
+/// `;`.
+///
+/// `mat2!(const r1; r2)` takes each row as a `V2` expression, e.g. `V2::X` or
+/// `vec2!(const ...)`, and can initialize a `const`. The scalar forms are always
+/// const-evaluable.
 #[macro_export]
 macro_rules! mat2 {
+    (const $($row: expr);+) => {
+        $crate::matrix::M2([$($row.0),+])
+    };
     ($e: expr) => {
         $crate::matrix::M2([[$e; 2]; 2])
     };
@@ -64,30 +115,22 @@ macro_rules! mat2 {
     };
     ($($e1: expr),+; $($e2: expr),+) => {
         {
-            use $crate::macros::types;
-            use $crate::macros::converter::Converter;
-            use $crate::macros::finalizer::Finalizer;
             use $crate::matrix::{self, FromVectors};
 
-            let e = types::EmptyConverter;
-            $(
-                let e = e.convert($e1);
-            )+
-            let v1: $crate::vector::V2<_> = e.finalize();
-
-            let e = types::EmptyConverter;
-            $(
-                let e = e.convert($e2);
-            )+
-            let v2: $crate::vector::V2<_> = e.finalize();
+            let v1 = $crate::__row!(2, 1, $($e1),+);
+            let v2 = $crate::__row!(2, 2, $($e2),+);
 
             matrix::M2::from_rows((v1, v2))
         }
     };
 }
 
+/// Builds an `M3`, see `mat2!` for the `const` form.
 #[macro_export]
 macro_rules! mat3 {
+    (const $($row: expr);+) => {
+        $crate::matrix::M3([$($row.0),+])
+    };
     ($e: expr) => {
         $crate::matrix::M3([[$e; 3]; 3])
     };
@@ -96,36 +139,23 @@ macro_rules! mat3 {
     };
     ($($e1: expr),+; $($e2: expr),+; $($e3: expr),+) => {
         {
-            use $crate::macros::types;
-            use $crate::macros::converter::Converter;
-            use $crate::macros::finalizer::Finalizer;
             use $crate::matrix::{self, FromVectors};
 
-            let e = types::EmptyConverter;
-            $(
-                let e = e.convert($e1);
-            )+
-            let v1: $crate::vector::V3<_> = e.finalize();
-
-            let e = types::EmptyConverter;
-            $(
-                let e = e.convert($e2);
-            )+
-            let v2: $crate::vector::V3<_> = e.finalize();
-
-            let e = types::EmptyConverter;
-            $(
-                let e = e.convert($e3);
-            )+
-            let v3: $crate::vector::V3<_> = e.finalize();
+            let v1 = $crate::__row!(3, 1, $($e1),+);
+            let v2 = $crate::__row!(3, 2, $($e2),+);
+            let v3 = $crate::__row!(3, 3, $($e3),+);
 
             matrix::M3::from_rows((v1, v2, v3))
         }
     };
 }
 
+/// Builds an `M4`, see `mat2!` for the `const` form.
 #[macro_export]
 macro_rules! mat4 {
+    (const $($row: expr);+) => {
+        $crate::matrix::M4([$($row.0),+])
+    };
     ($e: expr) => {
         $crate::matrix::M4([[$e; 4]; 4])
     };
@@ -134,36 +164,64 @@ macro_rules! mat4 {
     };
     ($($e1: expr),+; $($e2: expr),+; $($e3: expr),+; $($e4: expr),+) => {
         {
-            use $crate::macros::types;
-            use $crate::macros::converter::Converter;
-            use $crate::macros::finalizer::Finalizer;
             use $crate::matrix::{self, FromVectors};
 
-            let e = types::EmptyConverter;
-            $(
-                let e = e.convert($e1);
-            )+
-            let v1: $crate::vector::V4<_> = e.finalize();
+            let v1 = $crate::__row!(4, 1, $($e1),+);
+            let v2 = $crate::__row!(4, 2, $($e2),+);
+            let v3 = $crate::__row!(4, 3, $($e3),+);
+            let v4 = $crate::__row!(4, 4, $($e4),+);
 
-            let e = types::EmptyConverter;
-            $(
-                let e = e.convert($e2);
-            )+
-            let v2: $crate::vector::V4<_> = e.finalize();
+            matrix::M4::from_rows((v1, v2, v3, v4))
+        }
+    };
+}
 
-            let e = types::EmptyConverter;
-            $(
-                let e = e.convert($e3);
-            )+
-            let v3: $crate::vector::V4<_> = e.finalize();
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __glsl_mat {
+    ($n: expr, $e: expr) => {
+        {
+            let m: $crate::matrix::Matrix<_, $n, $n> =
+                $crate::macros::glsl::GlslConstructor::construct($e);
+            m
+        }
+    };
+    ($n: expr, $($e: expr),+) => {
+        {
+            use $crate::macros::arity::Arity;
+            use $crate::macros::converter::Converter;
 
-            let e = types::EmptyConverter;
+            let e = $crate::macros::glsl::EmptyColumns;
             $(
-                let e = e.convert($e4);
+                let e = e.expect_columns::<$n>().room().convert($e);
             )+
-            let v4: $crate::vector::V4<_> = e.finalize();
-
-            matrix::M4::from_rows((v1, v2, v3, v4))
+            e.expect_columns::<$n>().complete()
         }
     };
 }
+
+/// Builds an `M2` with GLSL's `mat2` semantics: a single scalar fills the diagonal, a single
+/// matrix is resized, and otherwise the arguments are consumed in column-major order, with integer
+/// components promoted as in `vec2!`. The component count is checked at compile time.
+#[macro_export]
+macro_rules! glsl_mat2 {
+    ($($e: expr),+) => {
+        $crate::__glsl_mat!(2, $($e),+)
+    };
+}
+
+/// Builds an `M3` with GLSL's `mat3` semantics, see `glsl_mat2!`.
+#[macro_export]
+macro_rules! glsl_mat3 {
+    ($($e: expr),+) => {
+        $crate::__glsl_mat!(3, $($e),+)
+    };
+}
+
+/// Builds an `M4` with GLSL's `mat4` semantics, see `glsl_mat2!`.
+#[macro_export]
+macro_rules! glsl_mat4 {
+    ($($e: expr),+) => {
+        $crate::__glsl_mat!(4, $($e),+)
+    };
+}
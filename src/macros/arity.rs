@@ -0,0 +1,257 @@
+//! Component-count checks performed by the constructor macros.
+//!
+//! Each check is a trait over the expected count `N` and the collected count `G`, so a mismatch
+//! is reported as a single error naming both numbers instead of a list of missing `Converter`
+//! and `Finalizer` impls.
+
+use super::glsl::{Columns, EmptyColumns};
+use super::types::{EmptyConverter, Overflow, V1};
+use crate::matrix::{Matrix, M2, M3, M4};
+use crate::vector::Vector;
+
+/// The number of components `G` collected so far.
+pub trait Count<const G: usize> {}
+
+impl Count<0> for EmptyConverter {}
+impl<T> Count<1> for V1<T> {}
+impl<T, const N: usize> Count<N> for Vector<T, N> {}
+impl<T> Count<4> for M2<T> {}
+impl<T> Count<9> for M3<T> {}
+impl<T> Count<16> for M4<T> {}
+/// More than four; `Room` has already reported the extra argument.
+impl<T> Count<5> for Overflow<T> {}
+impl Count<0> for EmptyColumns {}
+impl<T, const G: usize> Count<G> for Columns<T, G> {}
+
+#[diagnostic::on_unimplemented(
+    message = "too many arguments: vec{N}! expects {N} components, and the earlier arguments \
+               already provide them",
+    label = "an argument past the first {N} components would not be used",
+    note = "as in GLSL, every argument must contribute at least one component"
+)]
+pub trait Room<const N: usize, const G: usize> {}
+
+#[diagnostic::on_unimplemented(
+    message = "vec{N}! expects {N} components, got {G}",
+    label = "the arguments provide {G} component(s)"
+)]
+pub trait Complete<const N: usize, const G: usize> {}
+
+#[diagnostic::on_unimplemented(
+    message = "row {ROW} of mat{N}! expects {N} components, but the arguments provide more",
+    label = "this argument goes past the end of the row"
+)]
+pub trait RowFits<const N: usize, const G: usize, const ROW: usize> {}
+
+#[diagnostic::on_unimplemented(
+    message = "row {ROW} of mat{N}! expects {N} components, got {G}",
+    label = "the arguments provide {G} component(s)",
+    note = "a single scalar fills the whole row"
+)]
+pub trait RowComplete<const N: usize, const G: usize, const ROW: usize> {}
+
+#[diagnostic::on_unimplemented(
+    message = "too many arguments: glsl_mat{N}! expects {N} columns of {N} components, and the \
+               earlier arguments already provide them",
+    label = "an argument past the last column would not be used",
+    note = "as in GLSL, every argument must contribute at least one component"
+)]
+pub trait ColumnsRoom<const N: usize, const G: usize> {}
+
+#[diagnostic::on_unimplemented(
+    message = "glsl_mat{N}! expects {N} columns of {N} components, got {G} components",
+    label = "the arguments provide {G} component(s)"
+)]
+pub trait ColumnsComplete<const N: usize, const G: usize> {}
+
+macro_rules! impl_arity {
+    ($tr: ident, $n: expr => $($g: expr),+) => {
+        $(
+            impl $tr<$n, $g> for () {}
+        )+
+    };
+    ($tr: ident<ROW>, $n: expr => $($g: expr),+) => {
+        $(
+            impl<const ROW: usize> $tr<$n, $g, ROW> for () {}
+        )+
+    };
+}
+
+impl_arity!(Room, 2 => 0, 1);
+impl_arity!(Room, 3 => 0, 1, 2);
+impl_arity!(Room, 4 => 0, 1, 2, 3);
+impl_arity!(Complete, 2 => 2, 3, 4, 5);
+impl_arity!(Complete, 3 => 3, 4, 5);
+impl_arity!(Complete, 4 => 4, 5);
+impl_arity!(RowFits<ROW>, 2 => 1, 2);
+impl_arity!(RowFits<ROW>, 3 => 1, 2, 3);
+impl_arity!(RowFits<ROW>, 4 => 1, 2, 3, 4);
+// A count of one is a single scalar, which `V1` repeats across the row. Longer rows have been
+// reported by `RowFits`.
+impl_arity!(RowComplete<ROW>, 2 => 1, 2, 3, 4, 5);
+impl_arity!(RowComplete<ROW>, 3 => 1, 3, 4, 5);
+impl_arity!(RowComplete<ROW>, 4 => 1, 4, 5);
+impl_arity!(ColumnsRoom, 2 => 0, 1, 2, 3);
+impl_arity!(ColumnsRoom, 3 => 0, 1, 2, 3, 4, 5, 6, 7, 8);
+impl_arity!(ColumnsRoom, 4 => 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+// As for vectors, the last argument may provide more components than needed.
+impl_arity!(ColumnsComplete, 2 => 4, 5, 6, 7);
+impl_arity!(ColumnsComplete, 3 => 9, 10, 11, 12);
+impl_arity!(ColumnsComplete, 4 => 16, 17);
+
+/// Collected components `E` checked against an expected count `N`.
+pub struct Expect<E, const N: usize>(E);
+
+/// Collected components `E` of row `ROW` of a matrix with `N` columns.
+pub struct ExpectRow<E, const N: usize, const ROW: usize>(E);
+
+/// Collected components `E` of the columns of an `N`x`N` GLSL matrix constructor.
+pub struct ExpectColumns<E, const N: usize>(E);
+
+pub trait Arity: Sized {
+    /// Starts a check against `N`. Taking `self` as a method receiver makes sure the collected
+    /// type is known before `G` is inferred from it.
+    fn expect_components<const N: usize>(self) -> Expect<Self, N> {
+        Expect(self)
+    }
+
+    /// Starts a check of a matrix row against `N`, see `expect_components`.
+    fn expect_row<const N: usize, const ROW: usize>(self) -> ExpectRow<Self, N, ROW> {
+        ExpectRow(self)
+    }
+
+    /// Starts a check of the columns of an `N`x`N` matrix, see `expect_components`.
+    fn expect_columns<const N: usize>(self) -> ExpectColumns<Self, N> {
+        ExpectColumns(self)
+    }
+}
+
+impl<E> Arity for E {}
+
+/// Indexed access to the collected components in row-major order. Every collected type
+/// implements it, so the final checks build their result without a second bound that could fail
+/// next to the count check.
+pub trait Components {
+    type Item: Copy;
+
+    fn component(&self, i: usize) -> Self::Item;
+}
+
+impl<T> Components for V1<T>
+where
+    T: Copy,
+{
+    type Item = T;
+
+    fn component(&self, _: usize) -> T {
+        self.0
+    }
+}
+
+impl<T, const N: usize> Components for Vector<T, N>
+where
+    T: Copy,
+{
+    type Item = T;
+
+    fn component(&self, i: usize) -> T {
+        self.0[i]
+    }
+}
+
+impl<T> Components for Overflow<T>
+where
+    T: Copy,
+{
+    type Item = T;
+
+    fn component(&self, i: usize) -> T {
+        self.0[i]
+    }
+}
+
+impl<T, const G: usize> Components for Columns<T, G>
+where
+    T: Copy,
+{
+    type Item = T;
+
+    fn component(&self, i: usize) -> T {
+        self.0[i]
+    }
+}
+
+impl<T, const R: usize, const C: usize> Components for Matrix<T, R, C>
+where
+    T: Copy,
+{
+    type Item = T;
+
+    fn component(&self, i: usize) -> T {
+        self.0[i / C][i % C]
+    }
+}
+
+impl<E, const N: usize> Expect<E, N> {
+    /// Passes the components through if another argument can still contribute to them.
+    pub fn room<const G: usize>(self) -> E
+    where
+        E: Count<G>,
+        (): Room<N, G>,
+    {
+        self.0
+    }
+
+    /// The first `N` components, if the arguments provide at least `N` of them.
+    pub fn complete<const G: usize>(self) -> Vector<E::Item, N>
+    where
+        E: Count<G> + Components,
+        (): Complete<N, G>,
+    {
+        Vector(std::array::from_fn(|i| self.0.component(i)))
+    }
+}
+
+impl<E, const N: usize, const ROW: usize> ExpectRow<E, N, ROW> {
+    /// Passes the components through if the arguments so far still fit in the row.
+    pub fn fits<const G: usize>(self) -> E
+    where
+        E: Count<G>,
+        (): RowFits<N, G, ROW>,
+    {
+        self.0
+    }
+
+    /// The row, if the arguments provide at least `N` components; together with `fits` after
+    /// every argument, exactly `N`.
+    pub fn complete<const G: usize>(self) -> Vector<E::Item, N>
+    where
+        E: Count<G> + Components,
+        (): RowComplete<N, G, ROW>,
+    {
+        Vector(std::array::from_fn(|i| self.0.component(i)))
+    }
+}
+
+impl<E, const N: usize> ExpectColumns<E, N> {
+    /// Passes the components through if another argument can still contribute to them.
+    pub fn room<const G: usize>(self) -> E
+    where
+        E: Count<G>,
+        (): ColumnsRoom<N, G>,
+    {
+        self.0
+    }
+
+    /// The matrix, taking the components column by column, if the arguments provide at least
+    /// `N * N` of them.
+    pub fn complete<const G: usize>(self) -> Matrix<E::Item, N, N>
+    where
+        E: Count<G> + Components,
+        (): ColumnsComplete<N, G>,
+    {
+        Matrix(std::array::from_fn(|i| {
+            std::array::from_fn(|j| self.0.component(j * N + i))
+        }))
+    }
+}
@@ -1,15 +1,24 @@
-use super::types::{EmptyConverter, V1};
-use crate::numeric::Numeric;
+use super::promote::{Component, Promote};
+use super::types::{EmptyConverter, Overflow, V1};
 use crate::vector::{V2, V3, V4};
 use crate::matrix::{M2, M3, M4};
 
+/// Appends one macro argument to the components collected so far.
+///
+/// Arguments of different component types are combined through [`Promote`], so
+/// `vec4!(ivec2, 1.0, 0.0)` builds a floating point vector.
+#[diagnostic::on_unimplemented(
+    message = "`{F}` cannot be used as the next constructor argument",
+    label = "not a scalar, or does not fit after the components collected so far",
+    note = "arguments are scalars or vectors, adding up to at most four components"
+)]
 pub trait Converter<F, T> {
     fn convert(self, f: F) -> T;
 }
 
 impl<F> Converter<F, V1<F>> for EmptyConverter
 where
-    F: Numeric,
+    F: Component,
 {
     fn convert(self, f: F) -> V1<F> {
         V1(f)
@@ -20,7 +29,7 @@ macro_rules! impl_identity_converter {
     ($tt: tt) => {
         impl<F> Converter<$tt<F>, $tt<F>> for EmptyConverter
             where
-                F: Numeric,
+                F: Component,
             {
                 fn convert(self, f: $tt<F>) -> $tt<F> {
                     f
@@ -37,62 +46,95 @@ impl_identity_converter!(M3);
 impl_identity_converter!(M4);
 
 
-impl<F> Converter<F, V2<F>> for V1<F>
+impl<F, G> Converter<G, V2<F::Output>> for V1<F>
 where
-    F: Numeric,
+    F: Promote<G>,
+    G: Component,
 {
-    fn convert(self, f: F) -> V2<F> {
-        V2([*self, f])
+    fn convert(self, f: G) -> V2<F::Output> {
+        V2([self.0.promote_lhs(), F::promote_rhs(f)])
     }
 }
 
-impl<F> Converter<V2<F>, V3<F>> for V1<F>
+impl<F, G> Converter<V2<G>, V3<F::Output>> for V1<F>
 where
-    F: Numeric,
+    F: Promote<G>,
+    G: Component,
 {
-    fn convert(self, f: V2<F>) -> V3<F> {
+    fn convert(self, f: V2<G>) -> V3<F::Output> {
         let s = &f;
-        V3([*self, s[0], s[1]])
+        V3([self.0.promote_lhs(), F::promote_rhs(s[0]), F::promote_rhs(s[1])])
     }
 }
 
-impl<F> Converter<V3<F>, V4<F>> for V1<F>
+impl<F, G> Converter<V3<G>, V4<F::Output>> for V1<F>
 where
-    F: Numeric,
+    F: Promote<G>,
+    G: Component,
 {
-    fn convert(self, f: V3<F>) -> V4<F> {
+    fn convert(self, f: V3<G>) -> V4<F::Output> {
         let s = &f;
-        V4([*self, s[0], s[1], s[2]])
+        V4([
+            self.0.promote_lhs(),
+            F::promote_rhs(s[0]),
+            F::promote_rhs(s[1]),
+            F::promote_rhs(s[2]),
+        ])
     }
 }
 
-impl<F> Converter<F, V3<F>> for V2<F>
+impl<F, G> Converter<G, V3<F::Output>> for V2<F>
 where
-    F: Numeric,
+    F: Promote<G>,
+    G: Component,
 {
-    fn convert(self, f: F) -> V3<F> {
+    fn convert(self, f: G) -> V3<F::Output> {
         let s = &self;
-        V3([s[0], s[1], f])
+        V3([s[0].promote_lhs(), s[1].promote_lhs(), F::promote_rhs(f)])
     }
 }
 
-impl<F> Converter<V2<F>, V4<F>> for V2<F>
+impl<F, G> Converter<V2<G>, V4<F::Output>> for V2<F>
 where
-    F: Numeric,
+    F: Promote<G>,
+    G: Component,
 {
-    fn convert(self, f: V2<F>) -> V4<F> {
+    fn convert(self, f: V2<G>) -> V4<F::Output> {
         let s = &self;
         let t = &f;
-        V4([s[0], s[1], t[0], t[1]])
+        V4([
+            s[0].promote_lhs(),
+            s[1].promote_lhs(),
+            F::promote_rhs(t[0]),
+            F::promote_rhs(t[1]),
+        ])
     }
 }
 
-impl<F> Converter<F, V4<F>> for V3<F>
+impl<F, G> Converter<G, V4<F::Output>> for V3<F>
 where
-    F: Numeric,
+    F: Promote<G>,
+    G: Component,
 {
-    fn convert(self, f: F) -> V4<F> {
+    fn convert(self, f: G) -> V4<F::Output> {
         let s = &self;
-        V4([s[0], s[1], s[2], f])
+        V4([
+            s[0].promote_lhs(),
+            s[1].promote_lhs(),
+            s[2].promote_lhs(),
+            F::promote_rhs(f),
+        ])
+    }
+}
+
+impl<F, G> Converter<G, Overflow<F>> for V4<F> {
+    fn convert(self, _: G) -> Overflow<F> {
+        Overflow(self)
+    }
+}
+
+impl<F, G> Converter<G, Overflow<F>> for Overflow<F> {
+    fn convert(self, _: G) -> Overflow<F> {
+        self
     }
 }
@@ -1,8 +1,13 @@
+use super::promote::Component;
 use super::types::V1;
 use crate::matrix::{M2, M3, M4};
-use crate::numeric::Numeric;
 use crate::vector::{V2, V3, V4};
 
+/// Turns the collected components into the requested vector or matrix.
+#[diagnostic::on_unimplemented(
+    message = "a single `{Self}` argument cannot build a `{T}`",
+    note = "a single argument must be a scalar or a vector with at least as many components"
+)]
 pub trait Finalizer<T> {
     fn finalize(self) -> T;
 }
@@ -26,7 +31,7 @@ impl_identity_finalizer!(M4);
 
 impl<T> Finalizer<V2<T>> for V1<T>
 where
-    T: Numeric,
+    T: Component,
 {
     fn finalize(self) -> V2<T> {
         V2([*self; 2])
@@ -35,7 +40,7 @@ where
 
 impl<T> Finalizer<V3<T>> for V1<T>
 where
-    T: Numeric,
+    T: Component,
 {
     fn finalize(self) -> V3<T> {
         V3([*self; 3])
@@ -44,7 +49,7 @@ where
 
 impl<T> Finalizer<V4<T>> for V1<T>
 where
-    T: Numeric,
+    T: Component,
 {
     fn finalize(self) -> V4<T> {
         V4([*self; 4])
@@ -53,7 +58,7 @@ where
 
 impl<T> Finalizer<M2<T>> for V1<T>
 where
-    T: Numeric,
+    T: Component,
 {
     fn finalize(self) -> M2<T> {
         M2([[*self; 2], [*self; 2]])
@@ -62,7 +67,7 @@ where
 
 impl<T> Finalizer<M3<T>> for V1<T>
 where
-    T: Numeric,
+    T: Component,
 {
     fn finalize(self) -> M3<T> {
         M3([[*self; 3], [*self; 3], [*self; 3]])
@@ -71,7 +76,7 @@ where
 
 impl<T> Finalizer<M4<T>> for V1<T>
 where
-    T: Numeric,
+    T: Component,
 {
     fn finalize(self) -> M4<T> {
         M4([[*self; 4], [*self; 4], [*self; 4], [*self; 4]])
@@ -80,7 +85,7 @@ where
 
 impl<T> Finalizer<V2<T>> for V3<T>
 where
-    T: Numeric,
+    T: Component,
 {
     fn finalize(self) -> V2<T> {
         let s = &self;
@@ -90,7 +95,7 @@ where
 
 impl<T> Finalizer<V2<T>> for V4<T>
 where
-    T: Numeric,
+    T: Component,
 {
     fn finalize(self) -> V2<T> {
         let s = &self;
@@ -100,7 +105,7 @@ where
 
 impl<T> Finalizer<V3<T>> for V4<T>
 where
-    T: Numeric,
+    T: Component,
 {
     fn finalize(self) -> V3<T> {
         let s = &self;
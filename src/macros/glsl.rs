@@ -0,0 +1,151 @@
+use super::converter::Converter;
+use super::promote::{Component, Promote};
+use crate::matrix::Matrix;
+use crate::numeric::Numeric;
+use crate::vector::Vector;
+
+/// Single-argument GLSL matrix constructors, used by `glsl_mat2!` and friends.
+///
+/// - A scalar builds a diagonal matrix, so `glsl_mat4!(1.0)` is the identity.
+/// - A matrix is resized: larger sources keep their upper-left block, smaller ones are
+///   embedded in the identity.
+/// - `glsl_mat2!(v)` takes the four components of a `V4` as two columns.
+pub trait GlslConstructor<T> {
+    fn construct(self) -> T;
+}
+
+impl<F, const N: usize> GlslConstructor<Matrix<F, N, N>> for F
+where
+    F: Numeric,
+{
+    fn construct(self) -> Matrix<F, N, N> {
+        Matrix(std::array::from_fn(|i| {
+            std::array::from_fn(|j| if i == j { self } else { F::ZERO })
+        }))
+    }
+}
+
+impl<F, const R: usize, const C: usize, const N: usize> GlslConstructor<Matrix<F, N, N>>
+    for Matrix<F, R, C>
+where
+    F: Numeric,
+{
+    fn construct(self) -> Matrix<F, N, N> {
+        Matrix(std::array::from_fn(|i| {
+            std::array::from_fn(|j| {
+                if i < R && j < C {
+                    self[i][j]
+                } else if i == j {
+                    F::ONE
+                } else {
+                    F::ZERO
+                }
+            })
+        }))
+    }
+}
+
+impl<F> GlslConstructor<Matrix<F, 2, 2>> for Vector<F, 4>
+where
+    F: Component,
+{
+    fn construct(self) -> Matrix<F, 2, 2> {
+        Matrix([[self[0], self[2]], [self[1], self[3]]])
+    }
+}
+
+/// The components of a multi-argument GLSL matrix constructor, collected in column-major order.
+///
+/// `G` counts the components provided so far, up to `COLUMNS_OVERFLOW`. Only the first `G`
+/// of the sixteen slots are meaningful; the rest repeat the first component.
+pub struct Columns<T, const G: usize>(pub [T; 16]);
+
+/// The start of a multi-argument GLSL matrix constructor, before the first argument.
+pub struct EmptyColumns;
+
+/// Every count past the sixteen components of a `mat4`.
+const COLUMNS_OVERFLOW: usize = 17;
+
+/// The count after appending `k` components to `g`.
+const fn next_count(g: usize, k: usize) -> usize {
+    if g + k > COLUMNS_OVERFLOW {
+        COLUMNS_OVERFLOW
+    } else {
+        g + k
+    }
+}
+
+fn first<U, const K: usize>(rhs: [U; K]) -> [U; 16]
+where
+    U: Copy,
+{
+    std::array::from_fn(|i| rhs[if i < K { i } else { 0 }])
+}
+
+fn append<T, U, const G: usize, const K: usize>(lhs: [T; 16], rhs: [U; K]) -> [T::Output; 16]
+where
+    T: Promote<U>,
+    U: Copy,
+{
+    std::array::from_fn(|i| {
+        if i < G {
+            lhs[i].promote_lhs()
+        } else if i < G + K {
+            T::promote_rhs(rhs[i - G])
+        } else {
+            lhs[0].promote_lhs()
+        }
+    })
+}
+
+impl<U> Converter<U, Columns<U, 1>> for EmptyColumns
+where
+    U: Component,
+{
+    fn convert(self, f: U) -> Columns<U, 1> {
+        Columns(first([f]))
+    }
+}
+
+impl<U, const K: usize> Converter<Vector<U, K>, Columns<U, K>> for EmptyColumns
+where
+    U: Component,
+{
+    fn convert(self, f: Vector<U, K>) -> Columns<U, K> {
+        Columns(first(f.0))
+    }
+}
+
+macro_rules! impl_columns_converter {
+    ($($g: literal),+) => {
+        $(
+            impl<T, U> Converter<U, Columns<T::Output, { next_count($g, 1) }>> for Columns<T, $g>
+            where
+                T: Promote<U>,
+                U: Component,
+            {
+                fn convert(self, f: U) -> Columns<T::Output, { next_count($g, 1) }> {
+                    Columns(append::<T, U, $g, 1>(self.0, [f]))
+                }
+            }
+
+            impl_columns_converter!(@vector $g, 2);
+            impl_columns_converter!(@vector $g, 3);
+            impl_columns_converter!(@vector $g, 4);
+        )+
+    };
+    (@vector $g: literal, $k: literal) => {
+        impl<T, U> Converter<Vector<U, $k>, Columns<T::Output, { next_count($g, $k) }>>
+            for Columns<T, $g>
+        where
+            T: Promote<U>,
+            U: Component,
+        {
+            fn convert(self, f: Vector<U, $k>) -> Columns<T::Output, { next_count($g, $k) }> {
+                Columns(append::<T, U, $g, $k>(self.0, f.0))
+            }
+        }
+    };
+}
+
+impl_columns_converter!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17);
@@ -0,0 +1,82 @@
+use crate::numeric::Numeric;
+
+/// Scalar types accepted as individual arguments of the constructor macros.
+///
+/// Every `Numeric` type is a component; the unsigned integers are accepted as well so that
+/// they can be promoted into floating point vectors.
+pub trait Component: Copy {}
+
+impl<T> Component for T where T: Numeric {}
+impl Component for u8 {}
+impl Component for u16 {}
+impl Component for u32 {}
+impl Component for u64 {}
+
+/// The common component type of two constructor arguments.
+///
+/// Mirrors GLSL's implicit conversions: identical types are kept and integers are promoted to
+/// floating point. Integers of different widths and `f32`/`f64` are never mixed implicitly,
+/// which keeps untyped literals inferring from the surrounding context as before.
+#[diagnostic::on_unimplemented(
+    message = "cannot mix `{Self}` and `{Rhs}` components in a vector constructor",
+    label = "no implicit conversion between `{Self}` and `{Rhs}`",
+    note = "only integer components are promoted implicitly, and only to floating point; \
+            convert the other arguments explicitly, e.g. with `as` or `.cast()`"
+)]
+pub trait Promote<Rhs>: Copy
+where
+    Rhs: Copy,
+{
+    type Output;
+
+    fn promote_lhs(self) -> Self::Output;
+    fn promote_rhs(rhs: Rhs) -> Self::Output;
+}
+
+impl<T> Promote<T> for T
+where
+    T: Copy,
+{
+    type Output = T;
+
+    fn promote_lhs(self) -> T {
+        self
+    }
+
+    fn promote_rhs(rhs: T) -> T {
+        rhs
+    }
+}
+
+macro_rules! impl_promote_to_float {
+    ($float: ty => $($int: ty),+) => {
+        $(
+            impl Promote<$float> for $int {
+                type Output = $float;
+
+                fn promote_lhs(self) -> $float {
+                    self as $float
+                }
+
+                fn promote_rhs(rhs: $float) -> $float {
+                    rhs
+                }
+            }
+
+            impl Promote<$int> for $float {
+                type Output = $float;
+
+                fn promote_lhs(self) -> $float {
+                    self
+                }
+
+                fn promote_rhs(rhs: $int) -> $float {
+                    rhs as $float
+                }
+            }
+        )+
+    };
+}
+
+impl_promote_to_float!(f32 => i8, i16, i32, i64, u8, u16, u32, u64);
+impl_promote_to_float!(f64 => i8, i16, i32, i64, u8, u16, u32, u64);
@@ -1,12 +1,16 @@
-use crate::numeric::Numeric;
+use super::promote::Component;
+use crate::vector::V4;
 use std::ops::Deref;
 
 pub struct V1<T>(pub T);
 pub struct EmptyConverter;
+/// The components collected once the arguments go past four. Only reached after a count check
+/// has already failed, it absorbs the remaining arguments so they add no further errors.
+pub struct Overflow<T>(pub V4<T>);
 
 impl<T> Deref for V1<T>
 where
-    T: Numeric,
+    T: Component,
 {
     type Target = T;
 
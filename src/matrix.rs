@@ -1,24 +1,188 @@
+mod componentwise;
+mod constant;
+mod function;
+mod integer;
+mod iter;
 pub mod m2;
 pub mod m3;
 pub mod m4;
+mod ops;
+mod polar;
 
 use crate::float::Float;
-use std::ops::Div;
+use crate::numeric::{Identity, Numeric, Zero};
+use crate::scalar::Conjugate;
+use crate::vector::Vector;
+use std::ops::{Add, Deref, DerefMut, Div, Mul, Sub};
 
-#[derive(Default, Copy, Clone, PartialEq, Debug)]
-pub struct M2<T>(pub [[T; 2]; 2]);
+/// An `R` x `C` matrix stored as an array of rows.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Matrix<T, const R: usize, const C: usize>(pub [[T; C]; R]);
 
-#[derive(Default, Copy, Clone, PartialEq, Debug)]
-pub struct M3<T>(pub [[T; 3]; 3]);
+pub type M2<T> = Matrix<T, 2, 2>;
+pub type M3<T> = Matrix<T, 3, 3>;
+pub type M4<T> = Matrix<T, 4, 4>;
 
-#[derive(Default, Copy, Clone, PartialEq, Debug)]
-pub struct M4<T>(pub [[T; 4]; 4]);
+#[allow(non_snake_case)]
+pub const fn M2<T>(m: [[T; 2]; 2]) -> M2<T> {
+    Matrix(m)
+}
+
+#[allow(non_snake_case)]
+pub const fn M3<T>(m: [[T; 3]; 3]) -> M3<T> {
+    Matrix(m)
+}
+
+#[allow(non_snake_case)]
+pub const fn M4<T>(m: [[T; 4]; 4]) -> M4<T> {
+    Matrix(m)
+}
+
+impl<T, const R: usize, const C: usize> Default for Matrix<T, R, C>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Matrix(std::array::from_fn(|_| {
+            std::array::from_fn(|_| T::default())
+        }))
+    }
+}
+
+impl<T, const R: usize, const C: usize> Zero for Matrix<T, R, C>
+where
+    T: Numeric,
+{
+    const ZERO: Self = Matrix([[T::ZERO; C]; R]);
+}
+
+impl<T, const N: usize> Identity for Matrix<T, N, N>
+where
+    T: Numeric,
+{
+    const IDENTITY: Self = {
+        let mut m = [[T::ZERO; N]; N];
+        let mut i = 0;
+        while i < N {
+            m[i][i] = T::ONE;
+            i += 1;
+        }
+        Matrix(m)
+    };
+}
+
+impl<T, const R: usize, const C: usize> Deref for Matrix<T, R, C> {
+    type Target = [[T; C]; R];
 
-pub trait Matrix {
-    fn transpose(&mut self);
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const R: usize, const C: usize> DerefMut for Matrix<T, R, C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
+    pub const fn new(rows: [[T; C]; R]) -> Self {
+        Matrix(rows)
+    }
+
+    pub const fn splat(v: T) -> Self
+    where
+        T: Copy,
+    {
+        Matrix([[v; C]; R])
+    }
+
+    pub const fn element(&self, i: usize, j: usize) -> T
+    where
+        T: Copy,
+    {
+        self.0[i][j]
+    }
+
+    pub const fn row(&self, i: usize) -> Vector<T, C>
+    where
+        T: Copy,
+    {
+        Vector(self.0[i])
+    }
+}
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+    T: Copy,
+{
+    pub fn transposed(&self) -> Matrix<T, C, R> {
+        Matrix(std::array::from_fn(|i| std::array::from_fn(|j| self[j][i])))
+    }
 }
 
-pub trait FloatMatrix<F>: Matrix
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+    T: Copy + Zero,
+{
+    pub const fn col(&self, j: usize) -> Vector<T, R> {
+        let mut out = [T::ZERO; R];
+        let mut i = 0;
+        while i < R {
+            out[i] = self.0[i][j];
+            i += 1;
+        }
+        Vector(out)
+    }
+
+    /// `transposed` for `const` contexts, which need `Zero` to fill the result first.
+    pub const fn const_transposed(&self) -> Matrix<T, C, R> {
+        let mut out = [[T::ZERO; R]; C];
+        let mut i = 0;
+        while i < R {
+            let mut j = 0;
+            while j < C {
+                out[j][i] = self.0[i][j];
+                j += 1;
+            }
+            i += 1;
+        }
+        Matrix(out)
+    }
+}
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+    T: Conjugate,
+{
+    /// The conjugate transpose `Mᴴ`, which equals `transposed` for real components.
+    pub fn conjugate_transposed(&self) -> Matrix<T, C, R> {
+        Matrix(std::array::from_fn(|i| {
+            std::array::from_fn(|j| self[j][i].conj())
+        }))
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Copy,
+{
+    pub const fn transpose(&mut self) {
+        let mut i = 0;
+        while i < N {
+            let mut j = i + 1;
+            while j < N {
+                let e = self.0[i][j];
+                self.0[i][j] = self.0[j][i];
+                self.0[j][i] = e;
+                j += 1;
+            }
+            i += 1;
+        }
+    }
+}
+
+pub trait FloatMatrix<F>
 where
     F: Float,
     Self: Clone + Div<F, Output = Self>,
@@ -30,6 +194,59 @@ where
     }
 }
 
+/// Analytic functions of square matrices.
+///
+/// `exp` uses scaling and squaring with a diagonal Padé approximant, `log` uses inverse
+/// scaling and squaring with a Padé approximant evaluated in partial fractions, and `sqrt`
+/// uses the Denman-Beavers iteration. `log`, `sqrt` and `powf` return the principal branch,
+/// which exists when the matrix has no eigenvalues on the closed negative real axis.
+pub trait MatrixFunction<F>: FloatMatrix<F>
+where
+    F: Float,
+    Self:
+        Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Mul<F, Output = Self>,
+{
+    fn identity() -> Self;
+    /// Maximum absolute column sum.
+    fn norm_1(&self) -> F;
+
+    fn exp(&self) -> Self {
+        function::exp(*self)
+    }
+    fn log(&self) -> Self {
+        function::log(*self)
+    }
+    fn sqrt(&self) -> Self {
+        function::sqrt(*self)
+    }
+    fn powi(&self, n: i32) -> Self {
+        function::powi(*self, n)
+    }
+    fn powf(&self, p: F) -> Self {
+        (self.log() * p).exp()
+    }
+}
+
+impl<F, const N: usize> MatrixFunction<F> for Matrix<F, N, N>
+where
+    F: Float,
+    Matrix<F, N, N>: FloatMatrix<F>,
+{
+    fn identity() -> Self {
+        Matrix::IDENTITY
+    }
+
+    fn norm_1(&self) -> F {
+        (0..N)
+            .map(|j| (0..N).fold(F::default(), |sum, i| sum + self[i][j].f_abs()))
+            .fold(
+                F::default(),
+                |norm, sum| if sum > norm { sum } else { norm },
+            )
+    }
+}
+
+#[allow(clippy::wrong_self_convention)]
 pub trait IntoVectors<V> {
     fn into_cols(&self) -> V;
     fn into_rows(&self) -> V;
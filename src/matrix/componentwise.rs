@@ -0,0 +1,74 @@
+use crate::matrix::Matrix;
+use crate::numeric::Numeric;
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Applies `f` to every component, possibly changing the component type.
+    pub fn map<U, F>(self, mut f: F) -> Matrix<U, R, C>
+    where
+        F: FnMut(T) -> U,
+    {
+        Matrix(self.0.map(|row| row.map(&mut f)))
+    }
+
+    /// Combines the components of `self` and `other` pairwise.
+    pub fn zip_with<U, V, F>(self, other: Matrix<U, R, C>, mut f: F) -> Matrix<V, R, C>
+    where
+        T: Copy,
+        U: Copy,
+        F: FnMut(T, U) -> V,
+    {
+        Matrix(std::array::from_fn(|i| {
+            std::array::from_fn(|j| f(self[i][j], other[i][j]))
+        }))
+    }
+
+    /// Folds the components in row-major order.
+    pub fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        self.into_iter().fold(init, f)
+    }
+
+    /// Folds the components in row-major order using the first one as the initial value.
+    ///
+    /// Panics if the matrix has no components.
+    pub fn reduce<F>(self, f: F) -> T
+    where
+        F: FnMut(T, T) -> T,
+    {
+        self.into_iter()
+            .reduce(f)
+            .expect("reduce on a matrix without components")
+    }
+
+    /// Returns the smallest component. Panics if the matrix has no components.
+    pub fn min_element(self) -> T
+    where
+        T: PartialOrd,
+    {
+        self.reduce(|min, e| if e < min { e } else { min })
+    }
+
+    /// Returns the largest component. Panics if the matrix has no components.
+    pub fn max_element(self) -> T
+    where
+        T: PartialOrd,
+    {
+        self.reduce(|max, e| if e > max { e } else { max })
+    }
+
+    pub fn sum_elements(self) -> T
+    where
+        T: Numeric,
+    {
+        self.fold(T::default(), |sum, e| sum + e)
+    }
+
+    pub fn product_elements(self) -> T
+    where
+        T: Numeric,
+    {
+        self.fold(T::ONE, |product, e| product * e)
+    }
+}
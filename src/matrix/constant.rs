@@ -0,0 +1,86 @@
+//! `const fn` arithmetic for matrices of primitive numbers, see the vector counterpart.
+
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+
+macro_rules! impl_const_ops {
+    ($($t: ty),*) => {
+        $(
+            impl<const R: usize, const C: usize> Matrix<$t, R, C> {
+                pub const fn const_add(self, rhs: Self) -> Self {
+                    let mut out = self.0;
+                    let mut i = 0;
+                    while i < R {
+                        out[i] = Vector(out[i]).const_add(Vector(rhs.0[i])).0;
+                        i += 1;
+                    }
+                    Matrix(out)
+                }
+
+                pub const fn const_sub(self, rhs: Self) -> Self {
+                    let mut out = self.0;
+                    let mut i = 0;
+                    while i < R {
+                        out[i] = Vector(out[i]).const_sub(Vector(rhs.0[i])).0;
+                        i += 1;
+                    }
+                    Matrix(out)
+                }
+
+                pub const fn const_scale(self, s: $t) -> Self {
+                    let mut out = self.0;
+                    let mut i = 0;
+                    while i < R {
+                        out[i] = Vector(out[i]).const_scale(s).0;
+                        i += 1;
+                    }
+                    Matrix(out)
+                }
+
+                pub const fn const_neg(self) -> Self {
+                    let mut out = self.0;
+                    let mut i = 0;
+                    while i < R {
+                        out[i] = Vector(out[i]).const_neg().0;
+                        i += 1;
+                    }
+                    Matrix(out)
+                }
+
+                /// The matrix product, like `*`.
+                pub const fn const_mul<const K: usize>(
+                    self,
+                    rhs: Matrix<$t, C, K>,
+                ) -> Matrix<$t, R, K> {
+                    let mut out = [[0 as $t; K]; R];
+                    let mut i = 0;
+                    while i < R {
+                        let mut j = 0;
+                        while j < K {
+                            let mut k = 0;
+                            while k < C {
+                                out[i][j] += self.0[i][k] * rhs.0[k][j];
+                                k += 1;
+                            }
+                            j += 1;
+                        }
+                        i += 1;
+                    }
+                    Matrix(out)
+                }
+
+                pub const fn const_mul_vector(self, rhs: Vector<$t, C>) -> Vector<$t, R> {
+                    let mut out = [0 as $t; R];
+                    let mut i = 0;
+                    while i < R {
+                        out[i] = Vector(self.0[i]).const_dot(rhs);
+                        i += 1;
+                    }
+                    Vector(out)
+                }
+            }
+        )*
+    };
+}
+
+impl_const_ops!(i8, i16, i32, i64, f32, f64);
@@ -0,0 +1,136 @@
+use crate::float::Float;
+use crate::matrix::MatrixFunction;
+
+const MAX_ITERATIONS: usize = 64;
+const MAX_SQUARINGS: usize = 1100;
+
+/// Coefficients of the [6/6] Padé approximant of `exp(x)`.
+const EXP_PADE: [f64; 7] = [
+    1.0,
+    1.0 / 2.0,
+    5.0 / 44.0,
+    1.0 / 66.0,
+    1.0 / 792.0,
+    1.0 / 15840.0,
+    1.0 / 665280.0,
+];
+
+/// Gauss-Legendre nodes and weights on `[0, 1]`.
+///
+/// `sum(w * x / (1 + t * x))` over these pairs `(t, w)` is the [7/7] Padé approximant of
+/// `log(1 + x)`.
+const LOG_PADE: [(f64, f64); 7] = [
+    (0.025446043828620736, 0.06474248308443485),
+    (0.12923440720030277, 0.13985269574463832),
+    (0.2970774243113014, 0.19091502525255946),
+    (0.5, 0.2089795918367347),
+    (0.7029225756886985, 0.19091502525255946),
+    (0.8707655927996972, 0.13985269574463832),
+    (0.9745539561713793, 0.06474248308443485),
+];
+
+pub(crate) fn exp<F, M>(m: M) -> M
+where
+    F: Float,
+    M: MatrixFunction<F>,
+{
+    let half = F::f_from_f64(0.5);
+    let mut norm = m.norm_1();
+    let mut scale = F::ONE;
+    let mut squarings = 0;
+    while norm > half && squarings < MAX_SQUARINGS {
+        norm = norm * half;
+        scale = scale * half;
+        squarings += 1;
+    }
+
+    let a = m * scale;
+    let mut power = M::identity();
+    let mut n = M::identity();
+    let mut d = M::identity();
+    for (k, c) in EXP_PADE.iter().enumerate().skip(1) {
+        power = power * a;
+        let term = power * F::f_from_f64(*c);
+        n = n + term;
+        d = if k % 2 == 0 { d + term } else { d - term };
+    }
+
+    let mut r = d.inverse() * n;
+    for _ in 0..squarings {
+        r = r * r;
+    }
+    r
+}
+
+pub(crate) fn log<F, M>(m: M) -> M
+where
+    F: Float,
+    M: MatrixFunction<F>,
+{
+    let two = F::f_from_f64(2.0);
+    let quarter = F::f_from_f64(0.25);
+    let identity = M::identity();
+
+    let mut a = m;
+    let mut scale = F::ONE;
+    for _ in 0..MAX_ITERATIONS {
+        if (a - identity).norm_1() <= quarter {
+            break;
+        }
+        a = sqrt(a);
+        scale = scale * two;
+    }
+
+    let x = a - identity;
+    let mut r = identity * F::default();
+    for &(node, weight) in LOG_PADE.iter() {
+        r = r + x * (identity + x * F::f_from_f64(node)).inverse() * F::f_from_f64(weight);
+    }
+    r * scale
+}
+
+pub(crate) fn sqrt<F, M>(m: M) -> M
+where
+    F: Float,
+    M: MatrixFunction<F>,
+{
+    let half = F::f_from_f64(0.5);
+    let tolerance = F::EPSILON.f_sqrt();
+
+    let mut y = m;
+    let mut z = M::identity();
+    let mut converged = false;
+    for _ in 0..MAX_ITERATIONS {
+        let next = (y + z.inverse()) * half;
+        z = (z + y.inverse()) * half;
+        let diff = (next - y).norm_1();
+        y = next;
+
+        // Convergence is quadratic, so one more step after reaching sqrt(epsilon) is enough.
+        if converged {
+            break;
+        }
+        converged = diff <= tolerance * y.norm_1();
+    }
+    y
+}
+
+pub(crate) fn powi<F, M>(m: M, n: i32) -> M
+where
+    F: Float,
+    M: MatrixFunction<F>,
+{
+    let mut base = if n < 0 { m.inverse() } else { m };
+    let mut e = n.unsigned_abs();
+    let mut r = M::identity();
+    while e > 0 {
+        if e & 1 == 1 {
+            r = r * base;
+        }
+        e >>= 1;
+        if e > 0 {
+            base = base * base;
+        }
+    }
+    r
+}
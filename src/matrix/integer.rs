@@ -0,0 +1,132 @@
+use crate::matrix::Matrix;
+use crate::numeric::Numeric;
+use std::convert::TryFrom;
+
+/// Determinant of the `n` x `n` row-major matrix `m` by Bareiss fraction-free elimination.
+///
+/// Every intermediate value is a minor of the input, and every division is exact. `None` means
+/// an intermediate overflowed `i128`.
+fn bareiss(m: &mut [i128], n: usize) -> Option<i128> {
+    let mut sign = 1;
+    let mut prev = 1;
+    for k in 0..n.saturating_sub(1) {
+        if m[k * n + k] == 0 {
+            match (k + 1..n).find(|&i| m[i * n + k] != 0) {
+                Some(i) => {
+                    for j in 0..n {
+                        m.swap(k * n + j, i * n + j);
+                    }
+                    sign = -sign;
+                }
+                None => return Some(0),
+            }
+        }
+        let pivot = m[k * n + k];
+        for i in k + 1..n {
+            for j in k + 1..n {
+                let a = m[i * n + j].checked_mul(pivot)?;
+                let b = m[i * n + k].checked_mul(m[k * n + j])?;
+                m[i * n + j] = a.checked_sub(b)? / prev;
+            }
+        }
+        prev = pivot;
+    }
+    match n {
+        0 => Some(1),
+        _ => m[n * n - 1].checked_mul(sign),
+    }
+}
+
+/// Determinant of `m` with row `row` and column `col` removed.
+fn minor<const N: usize>(m: &[[i128; N]; N], row: usize, col: usize) -> Option<i128> {
+    let mut sub: Vec<i128> = (0..N)
+        .filter(|&i| i != row)
+        .flat_map(|i| (0..N).filter(move |&j| j != col).map(move |j| m[i][j]))
+        .collect();
+    bareiss(&mut sub, N - 1)
+}
+
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a.abs()
+}
+
+/// Exact linear algebra on integer matrices.
+///
+/// The computations run in `i128` with overflow checks. Each method returns `None` if
+/// an intermediate value or the result does not fit.
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Numeric + Into<i128> + TryFrom<i128>,
+{
+    fn widen(&self) -> [[i128; N]; N] {
+        self.0.map(|row| row.map(Into::into))
+    }
+
+    fn narrow(m: [[i128; N]; N]) -> Option<Matrix<T, N, N>> {
+        let mut out = [[T::default(); N]; N];
+        for i in 0..N {
+            for j in 0..N {
+                out[i][j] = T::try_from(m[i][j]).ok()?;
+            }
+        }
+        Some(Matrix(out))
+    }
+
+    fn wide_adjugate(&self) -> Option<[[i128; N]; N]> {
+        if N == 1 {
+            return Some([[1; N]; N]);
+        }
+        let m = self.widen();
+        let mut adj = [[0; N]; N];
+        for (i, row) in adj.iter_mut().enumerate() {
+            for (j, a) in row.iter_mut().enumerate() {
+                let d = minor(&m, j, i)?;
+                *a = if (i + j) % 2 == 0 { d } else { -d };
+            }
+        }
+        Some(adj)
+    }
+
+    /// Exact determinant by Bareiss fraction-free elimination.
+    pub fn checked_determinant(&self) -> Option<T> {
+        let mut m: Vec<i128> = self.widen().iter().flatten().copied().collect();
+        T::try_from(bareiss(&mut m, N)?).ok()
+    }
+
+    /// The adjugate, the transpose of the cofactor matrix. It satisfies
+    /// `self * adj == det * I` even when the matrix is singular.
+    pub fn checked_adjugate(&self) -> Option<Matrix<T, N, N>> {
+        Self::narrow(self.wide_adjugate()?)
+    }
+
+    /// The inverse as `(numerator, denominator)` in lowest terms, so that
+    /// `self * numerator == denominator * I` with `denominator > 0`.
+    ///
+    /// Returns `None` for singular matrices.
+    pub fn rational_inverse(&self) -> Option<(Matrix<T, N, N>, T)> {
+        let mut m: Vec<i128> = self.widen().iter().flatten().copied().collect();
+        let det = bareiss(&mut m, N)?;
+        if det == 0 {
+            return None;
+        }
+        let adj = self.wide_adjugate()?;
+        let divisor = adj.iter().flatten().fold(det, |g, &a| gcd(g, a)) * det.signum();
+        let numerator = Self::narrow(adj.map(|row| row.map(|a| a / divisor)))?;
+        Some((numerator, T::try_from(det / divisor).ok()?))
+    }
+
+    /// The integer inverse, which exists only when the determinant is `1` or `-1`.
+    pub fn checked_inverse(&self) -> Option<Matrix<T, N, N>> {
+        let (inverse, denominator) = self.rational_inverse()?;
+        if denominator.into() == 1 {
+            Some(inverse)
+        } else {
+            None
+        }
+    }
+}
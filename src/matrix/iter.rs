@@ -0,0 +1,108 @@
+use crate::matrix::Matrix;
+use crate::numeric::{Identity, Numeric};
+use crate::vector::Vector;
+use std::iter::{Flatten, Product, Sum};
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Iterates over the components in row-major order. `iter` still goes through `Deref` and
+    /// yields the rows.
+    pub fn iter_elements(&self) -> Flatten<std::slice::Iter<'_, [T; C]>> {
+        self.0.iter().flatten()
+    }
+
+    /// Iterates mutably over the components in row-major order.
+    pub fn iter_elements_mut(&mut self) -> Flatten<std::slice::IterMut<'_, [T; C]>> {
+        self.0.iter_mut().flatten()
+    }
+
+    /// Collects exactly `R * C` components in row-major order, returning `None` if the
+    /// iterator yields more or fewer. The iterator is not polled again once it has returned
+    /// `None`.
+    pub fn try_from_iter<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter().fuse();
+        let rows = [(); R].map(|_| Vector::try_from_iter(iter.by_ref().take(C)));
+        if iter.next().is_some() || rows.iter().any(Option::is_none) {
+            return None;
+        }
+        Some(Matrix(rows.map(|row| row.unwrap().0)))
+    }
+}
+
+impl<T, const R: usize, const C: usize> IntoIterator for Matrix<T, R, C> {
+    type Item = T;
+    type IntoIter = Flatten<std::array::IntoIter<[T; C], R>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter(self.0).flatten()
+    }
+}
+
+impl<'a, T, const R: usize, const C: usize> IntoIterator for &'a Matrix<T, R, C> {
+    type Item = &'a T;
+    type IntoIter = Flatten<std::slice::Iter<'a, [T; C]>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_elements()
+    }
+}
+
+impl<'a, T, const R: usize, const C: usize> IntoIterator for &'a mut Matrix<T, R, C> {
+    type Item = &'a mut T;
+    type IntoIter = Flatten<std::slice::IterMut<'a, [T; C]>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_elements_mut()
+    }
+}
+
+impl<T, const R: usize, const C: usize> Sum for Matrix<T, R, C>
+where
+    T: Numeric,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Matrix::default(), |sum, m| sum + m)
+    }
+}
+
+impl<'a, T, const R: usize, const C: usize> Sum<&'a Matrix<T, R, C>> for Matrix<T, R, C>
+where
+    T: Numeric,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.copied().sum()
+    }
+}
+
+/// Matrix product in iteration order; the product of no matrices is `Matrix::IDENTITY`.
+impl<T, const N: usize> Product for Matrix<T, N, N>
+where
+    T: Numeric,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Matrix::IDENTITY, |product, m| product * m)
+    }
+}
+
+impl<'a, T, const N: usize> Product<&'a Matrix<T, N, N>> for Matrix<T, N, N>
+where
+    T: Numeric,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.copied().product()
+    }
+}
@@ -1,28 +1,7 @@
 use crate::float::Float;
-use crate::matrix::{FloatMatrix, FromVectors, IntoVectors, Matrix, M4};
+use crate::matrix::{FloatMatrix, FromVectors, IntoVectors, M4};
 use crate::numeric::Numeric;
 use crate::vector::{Vector, V4};
-use std::ops::{Add, Deref, DerefMut, Div, Mul, Sub};
-
-impl<T> Deref for M4<T>
-where
-    T: Numeric,
-{
-    type Target = [[T; 4]; 4];
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
-impl<T> DerefMut for M4<T>
-where
-    T: Numeric,
-{
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
-    }
-}
 
 impl<T> IntoVectors<(V4<T>, V4<T>, V4<T>, V4<T>)> for M4<T>
 where
@@ -57,27 +36,11 @@ where
     }
 
     fn from_rows(v: (V4<T>, V4<T>, V4<T>, V4<T>)) -> Self {
-        let (V4(c1), V4(c2), V4(c3), V4(c4)) = v;
+        let (Vector(c1), Vector(c2), Vector(c3), Vector(c4)) = v;
         M4([c1, c2, c3, c4])
     }
 }
 
-impl<T> Matrix for M4<T>
-where
-    T: Numeric,
-{
-    fn transpose(&mut self) {
-        unsafe {
-            (&mut self[0][1] as *mut T).swap(&mut self[1][0]);
-            (&mut self[0][2] as *mut T).swap(&mut self[2][0]);
-            (&mut self[0][3] as *mut T).swap(&mut self[3][0]);
-            (&mut self[1][2] as *mut T).swap(&mut self[2][1]);
-            (&mut self[1][3] as *mut T).swap(&mut self[3][1]);
-            (&mut self[2][3] as *mut T).swap(&mut self[3][2]);
-        }
-    }
-}
-
 impl<F> FloatMatrix<F> for M4<F>
 where
     F: Float,
@@ -210,141 +173,3 @@ where
         ])
     }
 }
-
-impl<T> Add for M4<T>
-where
-    T: Numeric,
-{
-    type Output = M4<T>;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        M4([
-            [
-                self[0][0] + rhs[0][0],
-                self[0][1] + rhs[0][1],
-                self[0][2] + rhs[0][2],
-                self[0][3] + rhs[0][3],
-            ],
-            [
-                self[1][0] + rhs[1][0],
-                self[1][1] + rhs[1][1],
-                self[1][2] + rhs[1][2],
-                self[1][3] + rhs[1][3],
-            ],
-            [
-                self[2][0] + rhs[2][0],
-                self[2][1] + rhs[2][1],
-                self[2][2] + rhs[2][2],
-                self[2][3] + rhs[2][3],
-            ],
-            [
-                self[3][0] + rhs[3][0],
-                self[3][1] + rhs[3][1],
-                self[3][2] + rhs[3][2],
-                self[3][3] + rhs[3][3],
-            ],
-        ])
-    }
-}
-
-impl<T> Sub for M4<T>
-where
-    T: Numeric,
-{
-    type Output = M4<T>;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        M4([
-            [
-                self[0][0] - rhs[0][0],
-                self[0][1] - rhs[0][1],
-                self[0][2] - rhs[0][2],
-                self[0][3] - rhs[0][3],
-            ],
-            [
-                self[1][0] - rhs[1][0],
-                self[1][1] - rhs[1][1],
-                self[1][2] - rhs[1][2],
-                self[1][3] - rhs[1][3],
-            ],
-            [
-                self[2][0] - rhs[2][0],
-                self[2][1] - rhs[2][1],
-                self[2][2] - rhs[2][2],
-                self[2][3] - rhs[2][3],
-            ],
-            [
-                self[3][0] - rhs[3][0],
-                self[3][1] - rhs[3][1],
-                self[3][2] - rhs[3][2],
-                self[3][3] - rhs[3][3],
-            ],
-        ])
-    }
-}
-
-impl<T> Mul for M4<T>
-where
-    T: Numeric,
-{
-    type Output = M4<T>;
-
-    fn mul(self, rhs: Self) -> Self::Output {
-        let (c1, c2, c3, c4) = self.into_rows();
-        let (r1, r2, r3, r4) = rhs.into_cols();
-        M4([
-            [c1.dot(r1), c1.dot(r2), c1.dot(r3), c1.dot(r4)],
-            [c2.dot(r1), c2.dot(r2), c2.dot(r3), c2.dot(r4)],
-            [c3.dot(r1), c3.dot(r2), c3.dot(r3), c3.dot(r4)],
-            [c4.dot(r1), c4.dot(r2), c4.dot(r3), c4.dot(r4)],
-        ])
-    }
-}
-
-impl<T> Mul<V4<T>> for M4<T>
-where
-    T: Numeric,
-{
-    type Output = V4<T>;
-
-    fn mul(self, rhs: V4<T>) -> Self::Output {
-        let (c1, c2, c3, c4) = self.into_rows();
-        V4([c1.dot(rhs), c2.dot(rhs), c3.dot(rhs), c4.dot(rhs)])
-    }
-}
-
-impl<T> Div<T> for M4<T>
-where
-    T: Numeric,
-{
-    type Output = M4<T>;
-
-    fn div(self, rhs: T) -> Self::Output {
-        M4([
-            [
-                self[0][0] / rhs,
-                self[0][1] / rhs,
-                self[0][2] / rhs,
-                self[0][3] / rhs,
-            ],
-            [
-                self[1][0] / rhs,
-                self[1][1] / rhs,
-                self[1][2] / rhs,
-                self[1][3] / rhs,
-            ],
-            [
-                self[2][0] / rhs,
-                self[2][1] / rhs,
-                self[2][2] / rhs,
-                self[2][3] / rhs,
-            ],
-            [
-                self[3][0] / rhs,
-                self[3][1] / rhs,
-                self[3][2] / rhs,
-                self[3][3] / rhs,
-            ],
-        ])
-    }
-}
@@ -0,0 +1,129 @@
+use crate::matrix::Matrix;
+use crate::numeric::Numeric;
+use crate::vector::Vector;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+macro_rules! impl_componentwise_op {
+    ($op: ident, $f: ident, $($bound: tt)+) => {
+        impl<T, const R: usize, const C: usize> $op for Matrix<T, R, C>
+        where
+            T: $($bound)+,
+        {
+            type Output = Matrix<T, R, C>;
+
+            fn $f(self, rhs: Self) -> Self::Output {
+                Matrix(std::array::from_fn(|i| {
+                    std::array::from_fn(|j| self[i][j].$f(rhs[i][j]))
+                }))
+            }
+        }
+
+        forward_ref_binop!(
+            [T, const R: usize, const C: usize] $op, $f, Matrix<T, R, C>, Matrix<T, R, C>,
+            [T: $($bound)+]
+        );
+    };
+}
+
+impl_componentwise_op!(Add, add, Numeric);
+impl_componentwise_op!(Sub, sub, Numeric);
+// As in GLSL, `m1 / m2` is component-wise; `*` is the matrix product below.
+impl_componentwise_op!(Div, div, Numeric);
+impl_componentwise_op!(Rem, rem, Numeric + Rem<Output = T>);
+
+macro_rules! impl_scalar_op {
+    ($op: ident, $f: ident, $($bound: tt)+) => {
+        impl<T, const R: usize, const C: usize> $op<T> for Matrix<T, R, C>
+        where
+            T: $($bound)+,
+        {
+            type Output = Matrix<T, R, C>;
+
+            fn $f(self, rhs: T) -> Self::Output {
+                Matrix(std::array::from_fn(|i| {
+                    std::array::from_fn(|j| self[i][j].$f(rhs))
+                }))
+            }
+        }
+
+        forward_ref_binop!(
+            [T, const R: usize, const C: usize] $op, $f, Matrix<T, R, C>, T, [T: $($bound)+]
+        );
+    };
+}
+
+impl_scalar_op!(Add, add, Numeric);
+impl_scalar_op!(Sub, sub, Numeric);
+impl_scalar_op!(Mul, mul, Numeric);
+impl_scalar_op!(Div, div, Numeric);
+impl_scalar_op!(Rem, rem, Numeric + Rem<Output = T>);
+
+macro_rules! impl_assign_op {
+    ($op: ident, $f: ident, $binop: ident, $binf: ident) => {
+        impl<T, Rhs, const R: usize, const C: usize> $op<Rhs> for Matrix<T, R, C>
+        where
+            Matrix<T, R, C>: Copy + $binop<Rhs, Output = Matrix<T, R, C>>,
+        {
+            fn $f(&mut self, rhs: Rhs) {
+                *self = (*self).$binf(rhs);
+            }
+        }
+    };
+}
+
+impl_assign_op!(AddAssign, add_assign, Add, add);
+impl_assign_op!(SubAssign, sub_assign, Sub, sub);
+impl_assign_op!(MulAssign, mul_assign, Mul, mul);
+impl_assign_op!(DivAssign, div_assign, Div, div);
+impl_assign_op!(RemAssign, rem_assign, Rem, rem);
+
+impl<T, const R: usize, const C: usize> Neg for Matrix<T, R, C>
+where
+    T: Numeric,
+{
+    type Output = Matrix<T, R, C>;
+
+    fn neg(self) -> Self::Output {
+        Matrix(std::array::from_fn(|i| {
+            std::array::from_fn(|j| -self[i][j])
+        }))
+    }
+}
+
+forward_ref_unop!([T, const R: usize, const C: usize] Neg, neg, Matrix<T, R, C>, [T: Numeric]);
+
+impl<T, const R: usize, const C: usize, const K: usize> Mul<Matrix<T, C, K>> for Matrix<T, R, C>
+where
+    T: Numeric,
+{
+    type Output = Matrix<T, R, K>;
+
+    fn mul(self, rhs: Matrix<T, C, K>) -> Self::Output {
+        Matrix(std::array::from_fn(|i| {
+            std::array::from_fn(|j| {
+                (0..C).fold(T::default(), |sum, k| sum + self[i][k] * rhs[k][j])
+            })
+        }))
+    }
+}
+
+impl<T, const R: usize, const C: usize> Mul<Vector<T, C>> for Matrix<T, R, C>
+where
+    T: Numeric,
+{
+    type Output = Vector<T, R>;
+
+    fn mul(self, rhs: Vector<T, C>) -> Self::Output {
+        Vector(std::array::from_fn(|i| Vector(self[i]).dot(rhs)))
+    }
+}
+
+forward_ref_binop!(
+    [T, const R: usize, const C: usize, const K: usize] Mul, mul,
+    Matrix<T, R, C>, Matrix<T, C, K>, [T: Numeric]
+);
+forward_ref_binop!(
+    [T, const R: usize, const C: usize] Mul, mul, Matrix<T, R, C>, Vector<T, C>, [T: Numeric]
+);
@@ -0,0 +1,209 @@
+use crate::float::Float;
+use crate::matrix::M3;
+
+const MAX_SWEEPS: usize = 32;
+
+type Columns<F> = [[F; 3]; 3];
+
+impl<F> M3<F>
+where
+    F: Float,
+{
+    /// Splits the matrix into `(R, S)` such that `self == R * S`, where `R` is orthogonal
+    /// and `S` is symmetric positive semi-definite.
+    ///
+    /// `R` is a reflection when the determinant of `self` is negative. Use
+    /// `closest_rotation` when a proper rotation is required.
+    pub fn polar_decompose(&self) -> (M3<F>, M3<F>) {
+        let one = F::ONE;
+        let (u, sigma, v) = svd(self);
+        let sign = if sigma[2] < F::default() { -one } else { one };
+
+        let r = compose(&u, [one, one, sign], &v);
+        let s = compose(&v, [sigma[0], sigma[1], sigma[2].f_abs()], &v);
+        (r, s)
+    }
+
+    /// Returns the rotation (orthogonal with determinant `+1`) closest to the matrix in the
+    /// Frobenius norm.
+    pub fn closest_rotation(&self) -> M3<F> {
+        let one = F::ONE;
+        let (u, _, v) = svd(self);
+        compose(&u, [one; 3], &v)
+    }
+}
+
+/// Computes `self == U * diag(sigma) * V^T` with `det(U) == det(V) == 1`.
+///
+/// `sigma` is sorted by magnitude in descending order and only its last element may be
+/// negative. The decomposition uses one-sided Jacobi rotations, which keep small singular
+/// values accurate, and completes `U` with cross products so that rank-deficient inputs
+/// still yield an orthonormal basis.
+fn svd<F>(m: &M3<F>) -> (Columns<F>, [F; 3], Columns<F>)
+where
+    F: Float,
+{
+    let zero = F::ZERO;
+    let one = F::ONE;
+    let two = F::f_from_f64(2.0);
+
+    let mut b = m.0;
+    let mut v = [[zero; 3]; 3];
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = one;
+    }
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for &(p, q) in &[(0, 1), (0, 2), (1, 2)] {
+            let (mut alpha, mut beta, mut gamma) = (zero, zero, zero);
+            for row in &b {
+                alpha = alpha + row[p] * row[p];
+                beta = beta + row[q] * row[q];
+                gamma = gamma + row[p] * row[q];
+            }
+            if gamma.f_abs() <= F::EPSILON * alpha.f_sqrt() * beta.f_sqrt() {
+                continue;
+            }
+
+            let zeta = (beta - alpha) / (two * gamma);
+            let t = one / (zeta.f_abs() + (one + zeta * zeta).f_sqrt());
+            let t = if zeta < zero { -t } else { t };
+            let c = one / (one + t * t).f_sqrt();
+            let s = c * t;
+
+            rotate(&mut b, p, q, c, s);
+            rotate(&mut v, p, q, c, s);
+            rotated = true;
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let mut sigma = [zero; 3];
+    for (j, s) in sigma.iter_mut().enumerate() {
+        *s = dot(column(&b, j), column(&b, j)).f_sqrt();
+    }
+    for &(p, q) in &[(0, 1), (1, 2), (0, 1)] {
+        if sigma[p] < sigma[q] {
+            sigma.swap(p, q);
+            swap_columns(&mut b, p, q);
+            swap_columns(&mut v, p, q);
+        }
+    }
+
+    let u0 = if sigma[0] > zero {
+        scale(column(&b, 0), one / sigma[0])
+    } else {
+        [one, zero, zero]
+    };
+    let u1 = if sigma[1] > zero {
+        scale(column(&b, 1), one / sigma[1])
+    } else {
+        orthogonal(u0)
+    };
+    let u2 = cross(u0, u1);
+    sigma[2] = dot(column(&b, 2), u2);
+
+    let mut u = [[zero; 3]; 3];
+    for (i, row) in u.iter_mut().enumerate() {
+        *row = [u0[i], u1[i], u2[i]];
+    }
+    (u, sigma, v)
+}
+
+/// Computes `l * diag(d) * r^T`.
+fn compose<F>(l: &Columns<F>, d: [F; 3], r: &Columns<F>) -> M3<F>
+where
+    F: Float,
+{
+    let mut m = [[F::default(); 3]; 3];
+    for (i, row) in m.iter_mut().enumerate() {
+        for (j, e) in row.iter_mut().enumerate() {
+            for (k, d) in d.iter().enumerate() {
+                *e = *e + l[i][k] * r[j][k] * *d;
+            }
+        }
+    }
+    M3(m)
+}
+
+fn rotate<F>(m: &mut Columns<F>, p: usize, q: usize, c: F, s: F)
+where
+    F: Float,
+{
+    for row in m.iter_mut() {
+        let (x, y) = (row[p], row[q]);
+        row[p] = c * x - s * y;
+        row[q] = s * x + c * y;
+    }
+}
+
+/// Swaps two columns, negating one of them to preserve the determinant.
+fn swap_columns<F>(m: &mut Columns<F>, p: usize, q: usize)
+where
+    F: Float,
+{
+    for row in m.iter_mut() {
+        let x = row[p];
+        row[p] = row[q];
+        row[q] = -x;
+    }
+}
+
+fn column<F>(m: &Columns<F>, j: usize) -> [F; 3]
+where
+    F: Float,
+{
+    [m[0][j], m[1][j], m[2][j]]
+}
+
+fn dot<F>(a: [F; 3], b: [F; 3]) -> F
+where
+    F: Float,
+{
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross<F>(a: [F; 3], b: [F; 3]) -> [F; 3]
+where
+    F: Float,
+{
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn scale<F>(a: [F; 3], f: F) -> [F; 3]
+where
+    F: Float,
+{
+    [a[0] * f, a[1] * f, a[2] * f]
+}
+
+/// Returns a unit vector orthogonal to the unit vector `a`.
+fn orthogonal<F>(a: [F; 3]) -> [F; 3]
+where
+    F: Float,
+{
+    let zero = F::ZERO;
+    let one = F::ONE;
+
+    let mut k = 0;
+    for i in 1..3 {
+        if a[i].f_abs() < a[k].f_abs() {
+            k = i;
+        }
+    }
+    let mut w = scale(a, -a[k]);
+    w[k] = w[k] + one;
+    let len = dot(w, w).f_sqrt();
+    if len > zero {
+        scale(w, one / len)
+    } else {
+        w
+    }
+}
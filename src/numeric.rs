@@ -1,11 +1,25 @@
-use crate::matrix::{M2, M3, M4};
-use crate::vector::{V2, V3, V4};
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
+/// Element types of [`Vector`] and [`Matrix`].
+///
+/// Implementors must behave like a ring with exact `Copy` semantics: `Default` and [`Zero`] are
+/// the additive identity, [`One`] is the multiplicative identity, and `Div` only needs to be
+/// meaningful where the caller divides (integers truncate).
+/// Vector and matrix operators with the scalar on the right, such as `v * s`, are provided for
+/// every `Numeric`. Coherence rules out a blanket impl for the scalar on the left, so a
+/// downstream type opts in to `s * v` and `s * m` with [`impl_scalar_ops!`] or
+/// [`impl_scalar_op!`].
+///
+/// [`Vector`]: crate::vector::Vector
+/// [`Matrix`]: crate::matrix::Matrix
+/// [`impl_scalar_ops!`]: crate::impl_scalar_ops
+/// [`impl_scalar_op!`]: crate::impl_scalar_op
 pub trait Numeric
 where
     Self: Default
         + Copy
+        + Zero
+        + One
         + Add<Output = Self>
         + Sub<Output = Self>
         + Div<Output = Self>
@@ -14,90 +28,88 @@ where
 {
 }
 
-macro_rules! impl_mul {
-    ($t: ty) => {
-        impl std::ops::Mul<V2<$t>> for $t {
-            type Output = V2<$t>;
-
-            fn mul(self, rhs: V2<$t>) -> Self::Output {
-                V2([self * rhs[0], self * rhs[1]])
-            }
-        }
+/// The additive identity, e.g. `V3::ZERO` or `M4::ZERO`.
+pub trait Zero {
+    const ZERO: Self;
+}
 
-        impl std::ops::Mul<V3<$t>> for $t {
-            type Output = V3<$t>;
+/// The multiplicative identity. Vectors multiply component-wise, so `V3::ONE` has every
+/// component equal to one.
+pub trait One {
+    const ONE: Self;
+}
 
-            fn mul(self, rhs: V3<$t>) -> Self::Output {
-                V3([self * rhs[0], self * rhs[1], self * rhs[2]])
-            }
-        }
+/// The identity of matrix multiplication, e.g. `M4::IDENTITY`.
+pub trait Identity {
+    const IDENTITY: Self;
+}
 
-        impl std::ops::Mul<V4<$t>> for $t {
-            type Output = V4<$t>;
+/// Implements one left-scalar operator, `s op v` and `s op m`, for the scalar type `$t`
+/// against every [`Vector`] and [`Matrix`] of `$t`.
+///
+/// `impl_scalar_op!(Meters, Mul, mul)` covers a concrete type. Generic parameters go in a
+/// leading bracket, as in `impl_scalar_op!([T: Numeric] Wrapper<T>, Mul, mul)`.
+///
+/// [`Vector`]: crate::vector::Vector
+/// [`Matrix`]: crate::matrix::Matrix
+#[macro_export]
+macro_rules! impl_scalar_op {
+    ([$($g: tt)*] $t: ty, $op: ident, $f: ident) => {
+        impl<const N: usize, $($g)*> std::ops::$op<$crate::vector::Vector<$t, N>> for $t {
+            type Output = $crate::vector::Vector<$t, N>;
 
-            fn mul(self, rhs: V4<$t>) -> Self::Output {
-                V4([self * rhs[0], self * rhs[1], self * rhs[2], self * rhs[3]])
+            fn $f(self, rhs: $crate::vector::Vector<$t, N>) -> Self::Output {
+                $crate::vector::Vector(std::array::from_fn(|i| std::ops::$op::$f(self, rhs[i])))
             }
         }
 
-        impl std::ops::Mul<M2<$t>> for $t {
-            type Output = M2<$t>;
+        impl<const R: usize, const C: usize, $($g)*>
+            std::ops::$op<$crate::matrix::Matrix<$t, R, C>> for $t
+        {
+            type Output = $crate::matrix::Matrix<$t, R, C>;
 
-            fn mul(self, rhs: M2<$t>) -> Self::Output {
-                M2([
-                    [self * rhs[0][0], self * rhs[0][1]],
-                    [self * rhs[1][0], self * rhs[1][1]],
-                ])
+            fn $f(self, rhs: $crate::matrix::Matrix<$t, R, C>) -> Self::Output {
+                $crate::matrix::Matrix(std::array::from_fn(|i| {
+                    std::array::from_fn(|j| std::ops::$op::$f(self, rhs[i][j]))
+                }))
             }
         }
+    };
+}
 
-        impl std::ops::Mul<M3<$t>> for $t {
-            type Output = M3<$t>;
+/// Implements the full left-scalar operator set (`+ - * / %`) for `$t`, see
+/// [`impl_scalar_op!`](crate::impl_scalar_op). Types without `Rem` invoke `impl_scalar_op!` per
+/// operator instead.
+#[macro_export]
+macro_rules! impl_scalar_ops {
+    ([$($g: tt)*] $t: ty) => {
+        $crate::impl_scalar_op!([$($g)*] $t, Add, add);
+        $crate::impl_scalar_op!([$($g)*] $t, Sub, sub);
+        $crate::impl_scalar_op!([$($g)*] $t, Mul, mul);
+        $crate::impl_scalar_op!([$($g)*] $t, Div, div);
+        $crate::impl_scalar_op!([$($g)*] $t, Rem, rem);
+    };
+    ($t: ty) => {
+        $crate::impl_scalar_ops!([] $t);
+    };
+}
 
-            fn mul(self, rhs: M3<$t>) -> Self::Output {
-                M3([
-                    [self * rhs[0][0], self * rhs[0][1], self * rhs[0][2]],
-                    [self * rhs[1][0], self * rhs[1][1], self * rhs[1][2]],
-                    [self * rhs[2][0], self * rhs[2][1], self * rhs[2][2]],
-                ])
+macro_rules! impl_zero_one {
+    ($($t: ty: $zero: expr, $one: expr);*) => {
+        $(
+            impl Zero for $t {
+                const ZERO: $t = $zero;
             }
-        }
-
-        impl std::ops::Mul<M4<$t>> for $t {
-            type Output = M4<$t>;
 
-            fn mul(self, rhs: M4<$t>) -> Self::Output {
-                M4([
-                    [
-                        self * rhs[0][0],
-                        self * rhs[0][1],
-                        self * rhs[0][2],
-                        self * rhs[0][3],
-                    ],
-                    [
-                        self * rhs[1][0],
-                        self * rhs[1][1],
-                        self * rhs[1][2],
-                        self * rhs[1][3],
-                    ],
-                    [
-                        self * rhs[2][0],
-                        self * rhs[2][1],
-                        self * rhs[2][2],
-                        self * rhs[2][3],
-                    ],
-                    [
-                        self * rhs[3][0],
-                        self * rhs[3][1],
-                        self * rhs[3][2],
-                        self * rhs[3][3],
-                    ],
-                ])
+            impl One for $t {
+                const ONE: $t = $one;
             }
-        }
+        )*
     };
 }
 
+impl_zero_one!(i8: 0, 1; i16: 0, 1; i32: 0, 1; i64: 0, 1; f32: 0.0, 1.0; f64: 0.0, 1.0);
+
 impl Numeric for i8 {}
 impl Numeric for i16 {}
 impl Numeric for i32 {}
@@ -105,9 +117,9 @@ impl Numeric for i64 {}
 impl Numeric for f32 {}
 impl Numeric for f64 {}
 
-impl_mul!(i8);
-impl_mul!(i16);
-impl_mul!(i32);
-impl_mul!(i64);
-impl_mul!(f32);
-impl_mul!(f64);
+impl_scalar_ops!(i8);
+impl_scalar_ops!(i16);
+impl_scalar_ops!(i32);
+impl_scalar_ops!(i64);
+impl_scalar_ops!(f32);
+impl_scalar_ops!(f64);
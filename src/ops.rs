@@ -0,0 +1,57 @@
+/// Implements `lhs op &rhs`, `&lhs op rhs` and `&lhs op &rhs` by copying the operands into
+/// `lhs op rhs`.
+///
+/// Generic parameters and bounds are passed in brackets, e.g.
+/// `forward_ref_binop!([T, const N: usize] Add, add, Vector<T, N>, T, [T: Numeric]);`.
+macro_rules! forward_ref_binop {
+    ([$($g: tt)*] $op: ident, $f: ident, $lhs: ty, $rhs: ty, [$($w: tt)*]) => {
+        impl<'a, $($g)*> $op<&'a $rhs> for $lhs
+        where
+            $($w)*
+        {
+            type Output = <$lhs as $op<$rhs>>::Output;
+
+            fn $f(self, rhs: &'a $rhs) -> Self::Output {
+                $op::$f(self, *rhs)
+            }
+        }
+
+        impl<'a, $($g)*> $op<$rhs> for &'a $lhs
+        where
+            $($w)*
+        {
+            type Output = <$lhs as $op<$rhs>>::Output;
+
+            fn $f(self, rhs: $rhs) -> Self::Output {
+                $op::$f(*self, rhs)
+            }
+        }
+
+        impl<'a, 'b, $($g)*> $op<&'a $rhs> for &'b $lhs
+        where
+            $($w)*
+        {
+            type Output = <$lhs as $op<$rhs>>::Output;
+
+            fn $f(self, rhs: &'a $rhs) -> Self::Output {
+                $op::$f(*self, *rhs)
+            }
+        }
+    };
+}
+
+/// Implements `op &operand` by copying the operand into `op operand`.
+macro_rules! forward_ref_unop {
+    ([$($g: tt)*] $op: ident, $f: ident, $t: ty, [$($w: tt)*]) => {
+        impl<'a, $($g)*> $op for &'a $t
+        where
+            $($w)*
+        {
+            type Output = <$t as $op>::Output;
+
+            fn $f(self) -> Self::Output {
+                $op::$f(*self)
+            }
+        }
+    };
+}
@@ -0,0 +1,17 @@
+//! Scalar types beyond the primitives, usable as vector and matrix components.
+
+pub mod complex;
+pub mod double;
+pub mod dual;
+pub mod fixed;
+pub mod half;
+pub mod interval;
+pub mod precision;
+
+pub use self::complex::{Complex, Conjugate};
+pub use self::double::DD;
+pub use self::dual::{gradient, jacobian, Dual};
+pub use self::fixed::{Fixed, Q16_16, Q32_32};
+pub use self::half::f16;
+pub use self::interval::Interval;
+pub use self::precision::{Highp, Lowp, Mediump};
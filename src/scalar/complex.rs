@@ -0,0 +1,284 @@
+use crate::approx::ApproxEq;
+use crate::float::Float;
+use crate::numeric::{Numeric, One, Zero};
+use std::iter::{Product, Sum};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Scalars with a complex conjugate, used by [`Vector::hermitian_dot`] and
+/// [`Matrix::conjugate_transposed`]. Real types are their own conjugate.
+///
+/// [`Vector::hermitian_dot`]: crate::vector::Vector::hermitian_dot
+/// [`Matrix::conjugate_transposed`]: crate::matrix::Matrix::conjugate_transposed
+pub trait Conjugate: Numeric {
+    fn conj(self) -> Self;
+}
+
+macro_rules! impl_conjugate_real {
+    ($($t: ty),*) => {
+        $(
+            impl Conjugate for $t {
+                fn conj(self) -> $t {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_conjugate_real!(i8, i16, i32, i64, f32, f64);
+
+/// A complex number `re + im * i`.
+///
+/// There is no ordering and no `%`, so `Complex` is `Numeric` but not `Float`. Division uses
+/// the textbook formula and can overflow for components near the limits of `F`.
+#[derive(Copy, Clone, Default, PartialEq, Debug)]
+pub struct Complex<F> {
+    pub re: F,
+    pub im: F,
+}
+
+impl<F> Complex<F>
+where
+    F: Numeric,
+{
+    pub fn new(re: F, im: F) -> Complex<F> {
+        Complex { re, im }
+    }
+
+    /// `|z|²`, exact for exact component types.
+    pub fn norm_sqr(self) -> F {
+        self.re * self.re + self.im * self.im
+    }
+
+    pub fn scale(self, k: F) -> Complex<F> {
+        Complex::new(self.re * k, self.im * k)
+    }
+}
+
+impl<F> Complex<F>
+where
+    F: Float,
+{
+    /// The imaginary unit.
+    pub fn i() -> Complex<F> {
+        Complex::new(F::ZERO, F::ONE)
+    }
+
+    /// The modulus, scaled to avoid overflow in the intermediate squares. As with C's `hypot`,
+    /// an infinite part gives infinity even if the other part is NaN.
+    pub fn abs(self) -> F {
+        let (re, im) = (self.re.f_abs(), self.im.f_abs());
+        if re == F::INFINITY || im == F::INFINITY {
+            return F::INFINITY;
+        }
+        let m = if re > im { re } else { im };
+        if m == F::ZERO {
+            return m;
+        }
+        let (re, im) = (re / m, im / m);
+        m * (re * re + im * im).f_sqrt()
+    }
+
+    pub fn recip(self) -> Complex<F> {
+        self.conj().scale(F::ONE / self.norm_sqr())
+    }
+}
+
+impl<F> Conjugate for Complex<F>
+where
+    F: Numeric,
+{
+    fn conj(self) -> Complex<F> {
+        Complex::new(self.re, -self.im)
+    }
+}
+
+impl<F> From<F> for Complex<F>
+where
+    F: Numeric,
+{
+    fn from(re: F) -> Complex<F> {
+        Complex::new(re, F::default())
+    }
+}
+
+impl<F> Add for Complex<F>
+where
+    F: Numeric,
+{
+    type Output = Complex<F>;
+
+    fn add(self, rhs: Complex<F>) -> Complex<F> {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<F> Sub for Complex<F>
+where
+    F: Numeric,
+{
+    type Output = Complex<F>;
+
+    fn sub(self, rhs: Complex<F>) -> Complex<F> {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<F> Mul for Complex<F>
+where
+    F: Numeric,
+{
+    type Output = Complex<F>;
+
+    fn mul(self, rhs: Complex<F>) -> Complex<F> {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<F> Div for Complex<F>
+where
+    F: Numeric,
+{
+    type Output = Complex<F>;
+
+    fn div(self, rhs: Complex<F>) -> Complex<F> {
+        let d = rhs.norm_sqr();
+        let n = self * rhs.conj();
+        Complex::new(n.re / d, n.im / d)
+    }
+}
+
+impl<F> Neg for Complex<F>
+where
+    F: Numeric,
+{
+    type Output = Complex<F>;
+
+    fn neg(self) -> Complex<F> {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl<F> Sum for Complex<F>
+where
+    F: Numeric,
+{
+    fn sum<I: Iterator<Item = Complex<F>>>(iter: I) -> Complex<F> {
+        iter.fold(Complex::default(), Add::add)
+    }
+}
+
+impl<F> Product for Complex<F>
+where
+    F: Numeric,
+{
+    fn product<I: Iterator<Item = Complex<F>>>(iter: I) -> Complex<F> {
+        iter.fold(Complex::ONE, Mul::mul)
+    }
+}
+
+impl<F> Zero for Complex<F>
+where
+    F: Numeric,
+{
+    const ZERO: Complex<F> = Complex {
+        re: F::ZERO,
+        im: F::ZERO,
+    };
+}
+
+impl<F> One for Complex<F>
+where
+    F: Numeric,
+{
+    const ONE: Complex<F> = Complex {
+        re: F::ONE,
+        im: F::ZERO,
+    };
+}
+
+impl<F> Numeric for Complex<F> where F: Numeric {}
+
+impl_scalar_op!([F: Numeric] Complex<F>, Add, add);
+impl_scalar_op!([F: Numeric] Complex<F>, Sub, sub);
+impl_scalar_op!([F: Numeric] Complex<F>, Mul, mul);
+impl_scalar_op!([F: Numeric] Complex<F>, Div, div);
+
+impl<F> Complex<F>
+where
+    F: Float,
+{
+    pub fn from_polar(r: F, theta: F) -> Complex<F> {
+        Complex::new(r * theta.f_cos(), r * theta.f_sin())
+    }
+
+    /// The argument in `(-π, π]`.
+    pub fn arg(self) -> F {
+        self.im.f_atan2(self.re)
+    }
+
+    pub fn to_polar(self) -> (F, F) {
+        (self.abs(), self.arg())
+    }
+
+    pub fn exp(self) -> Complex<F> {
+        Complex::from_polar(self.re.f_exp(), self.im)
+    }
+
+    /// The principal logarithm, with imaginary part in `(-π, π]`.
+    pub fn ln(self) -> Complex<F> {
+        Complex::new(self.abs().f_ln(), self.arg())
+    }
+
+    /// The principal square root, with non-negative real part.
+    pub fn sqrt(self) -> Complex<F> {
+        let (r, theta) = self.to_polar();
+        Complex::from_polar(r.f_sqrt(), theta * F::f_from_f64(0.5))
+    }
+
+    pub fn powf(self, p: F) -> Complex<F> {
+        let (r, theta) = self.to_polar();
+        Complex::from_polar(r.f_powf(p), theta * p)
+    }
+}
+
+impl<F> ApproxEq for Complex<F>
+where
+    F: ApproxEq,
+{
+    type Epsilon = F::Epsilon;
+
+    fn default_epsilon() -> F::Epsilon {
+        F::default_epsilon()
+    }
+
+    fn default_max_relative() -> F::Epsilon {
+        F::default_max_relative()
+    }
+
+    fn default_max_ulps() -> u32 {
+        F::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Complex<F>, epsilon: F::Epsilon) -> bool {
+        self.re.abs_diff_eq(&other.re, epsilon) && self.im.abs_diff_eq(&other.im, epsilon)
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Complex<F>,
+        epsilon: F::Epsilon,
+        max_relative: F::Epsilon,
+    ) -> bool {
+        self.re.relative_eq(&other.re, epsilon, max_relative)
+            && self.im.relative_eq(&other.im, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Complex<F>, epsilon: F::Epsilon, max_ulps: u32) -> bool {
+        self.re.ulps_eq(&other.re, epsilon, max_ulps)
+            && self.im.ulps_eq(&other.im, epsilon, max_ulps)
+    }
+}
@@ -0,0 +1,515 @@
+use crate::approx::ApproxEq;
+use crate::cast::Cast;
+use crate::float::{Float, Libm};
+use crate::numeric::{Numeric, One, Zero};
+use crate::scalar::Conjugate;
+use std::iter::{Product, Sum};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// A double-double: the unevaluated sum `hi + lo` of two `f64`s with `|lo| <= ulp(hi) / 2`,
+/// giving about 106 bits of significand.
+///
+/// Arithmetic is built on error-free transformations, so `+`, `-` and `*` have a relative
+/// error around `2^-104`, and `/` and `f_sqrt` a small multiple of that. The exponent range is
+/// that of `f64`, and precision falls off below about `1e-292`, where `lo` becomes subnormal.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Copy, Clone, Default, PartialEq, PartialOrd, Debug)]
+pub struct DD {
+    hi: f64,
+    lo: f64,
+}
+
+/// `π`, `π/2` and `ln 2` as double-doubles.
+const PI: DD = DD {
+    hi: std::f64::consts::PI,
+    lo: 1.224_646_799_147_353_2e-16,
+};
+const FRAC_PI_2: DD = DD {
+    hi: std::f64::consts::FRAC_PI_2,
+    lo: 6.123_233_995_736_766e-17,
+};
+const LN_2: DD = DD {
+    hi: std::f64::consts::LN_2,
+    lo: 2.319_046_813_846_299_6e-17,
+};
+
+/// `π/2` and `ln 2` split into a head with few enough bits that multiplying it by the reduction
+/// multiple is exact, and a double-double tail.
+const FRAC_PI_2_HEAD: f64 = 1.570_796_326_734_125_6;
+const FRAC_PI_2_TAIL: DD = DD {
+    hi: 6.077_100_506_506_192e-11,
+    lo: 3.521_559_865_183_2e-27,
+};
+const LN_2_HEAD: f64 = 0.693_147_180_559_890_3;
+const LN_2_TAIL: DD = DD {
+    hi: 5.497_923_018_708_371e-14,
+    lo: 1.947_045_092_380_75e-31,
+};
+
+/// `a + b` exactly, as the rounded sum and its error.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// Like `two_sum`, but requires `|a| >= |b|`.
+fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+/// `a * b` exactly, as the rounded product and its error.
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+impl DD {
+    pub const ZERO: DD = DD { hi: 0.0, lo: 0.0 };
+    pub const ONE: DD = DD { hi: 1.0, lo: 0.0 };
+    /// `2^-104`.
+    pub const EPSILON: DD = DD {
+        hi: 4.930380657631324e-32,
+        lo: 0.0,
+    };
+
+    /// Normalizes `hi + lo` into a double-double.
+    pub fn new(hi: f64, lo: f64) -> DD {
+        let (hi, lo) = two_sum(hi, lo);
+        DD { hi, lo }
+    }
+
+    pub fn from_f64(v: f64) -> DD {
+        DD { hi: v, lo: 0.0 }
+    }
+
+    pub fn hi(self) -> f64 {
+        self.hi
+    }
+
+    pub fn lo(self) -> f64 {
+        self.lo
+    }
+
+    /// Rounds to the nearest `f64`.
+    pub fn to_f64(self) -> f64 {
+        self.hi
+    }
+
+    /// Rounds to the nearest `f32`, using `lo` to break the ties that rounding `hi` alone would
+    /// get wrong.
+    pub fn to_f32(self) -> f32 {
+        let r = self.hi as f32;
+        let d = self.hi - f64::from(r);
+        if d == 0.0 || self.lo == 0.0 || (self.lo > 0.0) != (d > 0.0) {
+            return r;
+        }
+        let other = if d > 0.0 { r.next_up() } else { r.next_down() };
+        if (f64::from(other) - self.hi).abs() == d.abs() {
+            other
+        } else {
+            r
+        }
+    }
+
+    pub fn floor(self) -> DD {
+        let hi = self.hi.floor();
+        if hi == self.hi {
+            let (hi, lo) = quick_two_sum(hi, self.lo.floor());
+            DD { hi, lo }
+        } else {
+            DD::from_f64(hi)
+        }
+    }
+
+    pub fn ceil(self) -> DD {
+        -(-self).floor()
+    }
+
+    pub fn trunc(self) -> DD {
+        if self.hi < 0.0 {
+            self.ceil()
+        } else {
+            self.floor()
+        }
+    }
+
+    fn mul_f64(self, b: f64) -> DD {
+        let (p, e) = two_prod(self.hi, b);
+        let (hi, lo) = quick_two_sum(p, e + self.lo * b);
+        DD { hi, lo }
+    }
+
+    /// `self * 2^k`, exact unless the result is subnormal.
+    fn scale(self, k: i32) -> DD {
+        let pow2 = |e: i32| f64::from_bits(((e + 1023) as u64) << 52);
+        let (a, b) = (pow2(k / 2), pow2(k - k / 2));
+        DD {
+            hi: self.hi * a * b,
+            lo: self.lo * a * b,
+        }
+    }
+
+    /// `(sin, cos)`, reducing by multiples of `π/2` that are exact below `2^20 · π/2`.
+    fn sin_cos(self) -> (DD, DD) {
+        if !self.hi.is_finite() {
+            let nan = DD::from_f64(f64::NAN);
+            return (nan, nan);
+        }
+        let k = (self.hi / FRAC_PI_2.hi).round_ties_even();
+        let r = (self - DD::from_f64(k * FRAC_PI_2_HEAD)) - FRAC_PI_2_TAIL.mul_f64(k);
+
+        // Taylor series on |r| <= π/4, where the 29th power term is below 2^-106.
+        let r2 = r * r;
+        let (mut sin, mut sin_term) = (r, r);
+        let (mut cos, mut cos_term) = (DD::ONE, DD::ONE);
+        for n in 1..15 {
+            let k = f64::from(2 * n);
+            cos_term = -(cos_term * r2) / DD::from_f64(k * (k - 1.0));
+            sin_term = -(sin_term * r2) / DD::from_f64(k * (k + 1.0));
+            cos = cos + cos_term;
+            sin = sin + sin_term;
+        }
+        match (k % 4.0) as i32 & 3 {
+            0 => (sin, cos),
+            1 => (cos, -sin),
+            2 => (-sin, -cos),
+            _ => (-cos, sin),
+        }
+    }
+}
+
+impl From<f64> for DD {
+    fn from(v: f64) -> DD {
+        DD::from_f64(v)
+    }
+}
+
+impl From<f32> for DD {
+    fn from(v: f32) -> DD {
+        DD::from_f64(f64::from(v))
+    }
+}
+
+impl Add for DD {
+    type Output = DD;
+
+    fn add(self, rhs: DD) -> DD {
+        let (s, e) = two_sum(self.hi, rhs.hi);
+        let (t, f) = two_sum(self.lo, rhs.lo);
+        let (s, e) = quick_two_sum(s, e + t);
+        let (hi, lo) = quick_two_sum(s, e + f);
+        DD { hi, lo }
+    }
+}
+
+impl Sub for DD {
+    type Output = DD;
+
+    fn sub(self, rhs: DD) -> DD {
+        self + -rhs
+    }
+}
+
+impl Mul for DD {
+    type Output = DD;
+
+    fn mul(self, rhs: DD) -> DD {
+        let (p, e) = two_prod(self.hi, rhs.hi);
+        let (hi, lo) = quick_two_sum(p, e + (self.hi * rhs.lo + self.lo * rhs.hi));
+        DD { hi, lo }
+    }
+}
+
+impl Div for DD {
+    type Output = DD;
+
+    /// Long division with three `f64` quotient digits.
+    fn div(self, rhs: DD) -> DD {
+        let q1 = self.hi / rhs.hi;
+        let r = self - rhs.mul_f64(q1);
+        let q2 = r.hi / rhs.hi;
+        let r = r - rhs.mul_f64(q2);
+        let q3 = r.hi / rhs.hi;
+        let (hi, lo) = quick_two_sum(q1, q2);
+        DD { hi, lo } + DD::from_f64(q3)
+    }
+}
+
+impl Rem for DD {
+    type Output = DD;
+
+    /// `a - b * trunc(a / b)`, like the primitive floats.
+    fn rem(self, rhs: DD) -> DD {
+        self - rhs * (self / rhs).trunc()
+    }
+}
+
+impl Neg for DD {
+    type Output = DD;
+
+    fn neg(self) -> DD {
+        DD {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl Sum for DD {
+    fn sum<I: Iterator<Item = DD>>(iter: I) -> DD {
+        iter.fold(DD::ZERO, Add::add)
+    }
+}
+
+impl Product for DD {
+    fn product<I: Iterator<Item = DD>>(iter: I) -> DD {
+        iter.fold(DD::ONE, Mul::mul)
+    }
+}
+
+impl Zero for DD {
+    const ZERO: DD = DD::ZERO;
+}
+
+impl One for DD {
+    const ONE: DD = DD::ONE;
+}
+
+impl Numeric for DD {}
+
+impl Conjugate for DD {
+    fn conj(self) -> DD {
+        self
+    }
+}
+
+impl_scalar_ops!(DD);
+
+/// The transcendental functions refine the `f64` result or reduce the argument with
+/// double-double constants, and are accurate to a few `EPSILON`. `f_sin`, `f_cos` and `f_tan`
+/// lose accuracy beyond `|x| ≈ 2^20 · π/2`.
+impl Float for DD {
+    const PI: DD = PI;
+    const EPSILON: DD = DD::EPSILON;
+    const INFINITY: DD = DD {
+        hi: f64::INFINITY,
+        lo: 0.0,
+    };
+    const NAN: DD = DD {
+        hi: f64::NAN,
+        lo: f64::NAN,
+    };
+
+    fn f_is_nan(self) -> bool {
+        self.hi.is_nan()
+    }
+
+    fn f_is_finite(self) -> bool {
+        self.hi.is_finite()
+    }
+
+    fn f_abs(self) -> DD {
+        if self.hi < 0.0 {
+            -self
+        } else {
+            self
+        }
+    }
+
+    fn f_copysign(self, sign: DD) -> DD {
+        if self.hi.is_sign_negative() == sign.hi.is_sign_negative() {
+            self
+        } else {
+            -self
+        }
+    }
+
+    fn f_floor(self) -> DD {
+        self.floor()
+    }
+
+    fn f_ceil(self) -> DD {
+        self.ceil()
+    }
+
+    /// One Newton step from the `f64` square root. Negative values give `NaN`.
+    fn f_sqrt(self) -> DD {
+        if self.hi <= 0.0 {
+            return DD::from_f64(self.hi.sqrt());
+        }
+        let s = self.hi.sqrt();
+        let (p, e) = two_prod(s, s);
+        let r = self - DD { hi: p, lo: e };
+        let (hi, lo) = quick_two_sum(s, r.hi / (2.0 * s));
+        DD { hi, lo }
+    }
+
+    /// Reduces to `|r| <= ln(2) / 2048`, sums the series of `e^r - 1` and squares back with
+    /// `e^2r - 1 = (e^r - 1)(e^r + 1)`, which keeps the small result accurate.
+    fn f_exp(self) -> DD {
+        if self.hi.is_nan() {
+            return self;
+        }
+        if self.hi > 709.8 {
+            return DD::INFINITY;
+        }
+        if self.hi < -745.2 {
+            return DD::ZERO;
+        }
+        let k = (self.hi / LN_2.hi).round_ties_even();
+        let r = ((self - DD::from_f64(k * LN_2_HEAD)) - LN_2_TAIL.mul_f64(k)).scale(-10);
+        let (mut s, mut term) = (r, r);
+        for n in 2..10 {
+            term = term * r / DD::from_f64(f64::from(n));
+            s = s + term;
+        }
+        for _ in 0..10 {
+            s = s * (s + DD::from_f64(2.0));
+        }
+        (s + DD::ONE).scale(k as i32)
+    }
+
+    /// One Newton step on `e^y = self` from the `f64` logarithm.
+    fn f_ln(self) -> DD {
+        if self.hi <= 0.0 || !self.hi.is_finite() {
+            return DD::from_f64(self.hi.l_ln());
+        }
+        if self.hi < 1e-300 {
+            // Keep `e^-y` below the overflow threshold.
+            return self.scale(1000).f_ln() - LN_2.mul_f64(1000.0);
+        }
+        let y = DD::from_f64(self.hi.l_ln());
+        y + self * (-y).f_exp() - DD::ONE
+    }
+
+    fn f_sin(self) -> DD {
+        self.sin_cos().0
+    }
+
+    fn f_cos(self) -> DD {
+        self.sin_cos().1
+    }
+
+    fn f_tan(self) -> DD {
+        let (s, c) = self.sin_cos();
+        s / c
+    }
+
+    /// One Newton step on `x sin θ - y cos θ = 0` from the `f64` angle.
+    fn f_atan2(self, x: DD) -> DD {
+        let t = DD::from_f64(self.hi.l_atan2(x.hi));
+        if !self.hi.is_finite() || !x.hi.is_finite() || (self.hi == 0.0 && x.hi == 0.0) {
+            return t;
+        }
+        let (s, c) = t.sin_cos();
+        t - (x * s - self * c) / (x * c + self * s)
+    }
+
+    fn f_from_f64(v: f64) -> DD {
+        DD::from_f64(v)
+    }
+}
+
+impl ApproxEq for DD {
+    type Epsilon = DD;
+
+    fn default_epsilon() -> DD {
+        DD::EPSILON
+    }
+
+    fn default_max_relative() -> DD {
+        DD::EPSILON
+    }
+
+    fn default_max_ulps() -> u32 {
+        4
+    }
+
+    fn abs_diff_eq(&self, other: &DD, epsilon: DD) -> bool {
+        self == other || (*self - *other).f_abs() <= epsilon
+    }
+
+    fn relative_eq(&self, other: &DD, epsilon: DD, max_relative: DD) -> bool {
+        if self.abs_diff_eq(other, epsilon) {
+            return true;
+        }
+        if !self.f_is_finite() || !other.f_is_finite() {
+            return false;
+        }
+        let (a, b) = (self.f_abs(), other.f_abs());
+        let largest = if a > b { a } else { b };
+        (*self - *other).f_abs() <= largest * max_relative
+    }
+
+    /// Counts units of `2^-104` relative to the larger magnitude, since a double-double has no
+    /// fixed bit pattern to step through.
+    fn ulps_eq(&self, other: &DD, epsilon: DD, max_ulps: u32) -> bool {
+        let max_relative = DD::EPSILON.mul_f64(f64::from(max_ulps));
+        self.relative_eq(other, epsilon, max_relative)
+    }
+}
+
+macro_rules! impl_cast_double {
+    ($t: ty, $to: ident) => {
+        impl Cast<DD> for $t {
+            fn cast(self) -> DD {
+                DD::from(self)
+            }
+
+            fn saturating_cast(self) -> DD {
+                DD::from(self)
+            }
+
+            fn checked_cast(self) -> Option<DD> {
+                Some(DD::from(self))
+            }
+        }
+
+        impl Cast<$t> for DD {
+            fn cast(self) -> $t {
+                self.$to()
+            }
+
+            fn saturating_cast(self) -> $t {
+                let v = self.$to();
+                if v.is_infinite() && self.hi.is_finite() {
+                    if v > 0.0 {
+                        <$t>::MAX
+                    } else {
+                        <$t>::MIN
+                    }
+                } else {
+                    v
+                }
+            }
+
+            fn checked_cast(self) -> Option<$t> {
+                let v = self.$to();
+                if v.is_infinite() && self.hi.is_finite() {
+                    None
+                } else {
+                    Some(v)
+                }
+            }
+        }
+    };
+}
+
+impl_cast_double!(f32, to_f32);
+impl_cast_double!(f64, to_f64);
+
+impl Cast<DD> for DD {
+    fn cast(self) -> DD {
+        self
+    }
+
+    fn saturating_cast(self) -> DD {
+        self
+    }
+
+    fn checked_cast(self) -> Option<DD> {
+        Some(self)
+    }
+}
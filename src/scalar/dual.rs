@@ -0,0 +1,458 @@
+use crate::approx::ApproxEq;
+use crate::float::Float;
+use crate::matrix::Matrix;
+use crate::numeric::{Numeric, One, Zero};
+use crate::scalar::Conjugate;
+use crate::vector::Vector;
+use std::iter::{Product, Sum};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// A dual number `value + deriv * ε` with `ε² = 0`, for forward-mode automatic
+/// differentiation.
+///
+/// Evaluating a function on `Dual::variable(x)` yields `f(x)` in `value` and `f'(x)` in
+/// `deriv`. Ordering compares `value` first.
+#[derive(Copy, Clone, Default, PartialEq, PartialOrd, Debug)]
+pub struct Dual<F> {
+    pub value: F,
+    pub deriv: F,
+}
+
+impl<F> Dual<F>
+where
+    F: Float,
+{
+    pub fn new(value: F, deriv: F) -> Dual<F> {
+        Dual { value, deriv }
+    }
+
+    /// A value that does not depend on the variable.
+    pub fn constant(value: F) -> Dual<F> {
+        Dual::constant_of(value)
+    }
+
+    /// The variable being differentiated with respect to.
+    pub fn variable(value: F) -> Dual<F> {
+        Dual::new(value, F::ONE)
+    }
+
+    /// `constant` for the associated consts, which cannot call trait methods.
+    const fn constant_of(value: F) -> Dual<F> {
+        Dual {
+            value,
+            deriv: F::ZERO,
+        }
+    }
+
+    /// Applies a function with value `f` and derivative `df` at `self.value` (chain rule).
+    ///
+    /// A constant stays constant even where `df` is infinite, such as `sqrt` at zero, instead
+    /// of picking up a `0 * inf = NaN` derivative.
+    fn chain(self, f: F, df: F) -> Dual<F> {
+        if self.deriv == F::ZERO {
+            return Dual::constant(f);
+        }
+        Dual::new(f, df * self.deriv)
+    }
+
+    /// Whether both operands are constants, whose result is a constant as in `chain`.
+    fn both_constant(self, rhs: Dual<F>) -> bool {
+        self.deriv == F::ZERO && rhs.deriv == F::ZERO
+    }
+}
+
+impl<F> From<F> for Dual<F>
+where
+    F: Float,
+{
+    fn from(value: F) -> Dual<F> {
+        Dual::constant(value)
+    }
+}
+
+impl<F> Add for Dual<F>
+where
+    F: Float,
+{
+    type Output = Dual<F>;
+
+    fn add(self, rhs: Dual<F>) -> Dual<F> {
+        Dual::new(self.value + rhs.value, self.deriv + rhs.deriv)
+    }
+}
+
+impl<F> Sub for Dual<F>
+where
+    F: Float,
+{
+    type Output = Dual<F>;
+
+    fn sub(self, rhs: Dual<F>) -> Dual<F> {
+        Dual::new(self.value - rhs.value, self.deriv - rhs.deriv)
+    }
+}
+
+impl<F> Mul for Dual<F>
+where
+    F: Float,
+{
+    type Output = Dual<F>;
+
+    fn mul(self, rhs: Dual<F>) -> Dual<F> {
+        if self.both_constant(rhs) {
+            return Dual::constant(self.value * rhs.value);
+        }
+        Dual::new(
+            self.value * rhs.value,
+            self.value * rhs.deriv + self.deriv * rhs.value,
+        )
+    }
+}
+
+impl<F> Div for Dual<F>
+where
+    F: Float,
+{
+    type Output = Dual<F>;
+
+    fn div(self, rhs: Dual<F>) -> Dual<F> {
+        if self.both_constant(rhs) {
+            return Dual::constant(self.value / rhs.value);
+        }
+        Dual::new(
+            self.value / rhs.value,
+            (self.deriv * rhs.value - self.value * rhs.deriv) / (rhs.value * rhs.value),
+        )
+    }
+}
+
+impl<F> Rem for Dual<F>
+where
+    F: Float + Rem<Output = F>,
+{
+    type Output = Dual<F>;
+
+    /// `a % b = a - b * trunc(a / b)`, where the truncated quotient is locally constant.
+    fn rem(self, rhs: Dual<F>) -> Dual<F> {
+        let value = self.value % rhs.value;
+        if self.both_constant(rhs) {
+            return Dual::constant(value);
+        }
+        let quotient = (self.value - value) / rhs.value;
+        Dual::new(value, self.deriv - rhs.deriv * quotient)
+    }
+}
+
+impl<F> Neg for Dual<F>
+where
+    F: Float,
+{
+    type Output = Dual<F>;
+
+    fn neg(self) -> Dual<F> {
+        Dual::new(-self.value, -self.deriv)
+    }
+}
+
+impl<F> Sum for Dual<F>
+where
+    F: Float,
+{
+    fn sum<I: Iterator<Item = Dual<F>>>(iter: I) -> Dual<F> {
+        iter.fold(Dual::default(), Add::add)
+    }
+}
+
+impl<F> Product for Dual<F>
+where
+    F: Float,
+{
+    fn product<I: Iterator<Item = Dual<F>>>(iter: I) -> Dual<F> {
+        iter.fold(Dual::ONE, Mul::mul)
+    }
+}
+
+impl<F> Zero for Dual<F>
+where
+    F: Float,
+{
+    const ZERO: Dual<F> = Dual::constant_of(F::ZERO);
+}
+
+impl<F> One for Dual<F>
+where
+    F: Float,
+{
+    const ONE: Dual<F> = Dual::constant_of(F::ONE);
+}
+
+impl<F> Numeric for Dual<F> where F: Float {}
+
+impl<F> Conjugate for Dual<F>
+where
+    F: Float,
+{
+    fn conj(self) -> Dual<F> {
+        self
+    }
+}
+
+impl_scalar_ops!([F: Float + Rem<Output = F>] Dual<F>);
+
+impl<F> Dual<F>
+where
+    F: Float,
+{
+    pub fn recip(self) -> Dual<F> {
+        let r = F::ONE / self.value;
+        self.chain(r, -r * r)
+    }
+
+    pub fn exp(self) -> Dual<F> {
+        let e = self.value.f_exp();
+        self.chain(e, e)
+    }
+
+    pub fn ln(self) -> Dual<F> {
+        self.chain(self.value.f_ln(), F::ONE / self.value)
+    }
+
+    pub fn sin(self) -> Dual<F> {
+        self.chain(self.value.f_sin(), self.value.f_cos())
+    }
+
+    pub fn cos(self) -> Dual<F> {
+        self.chain(self.value.f_cos(), -self.value.f_sin())
+    }
+
+    pub fn tan(self) -> Dual<F> {
+        let t = self.value.f_tan();
+        self.chain(t, F::ONE + t * t)
+    }
+
+    pub fn atan(self) -> Dual<F> {
+        self.chain(
+            self.value.f_atan(),
+            F::ONE / (F::ONE + self.value * self.value),
+        )
+    }
+
+    pub fn powi(self, n: i32) -> Dual<F> {
+        // `x^0` is constant, but `0 * 0^-1` would make its derivative NaN at zero.
+        if n == 0 {
+            return Dual::constant(F::ONE);
+        }
+        self.chain(
+            self.value.f_powi(n),
+            F::f_from_i32(n) * self.value.f_powi(n - 1),
+        )
+    }
+
+    pub fn powf(self, p: F) -> Dual<F> {
+        self.chain(self.value.f_powf(p), p * self.value.f_powf(p - F::ONE))
+    }
+}
+
+/// Every constant has a zero derivative. Piecewise constant functions such as `f_floor` have a
+/// zero derivative, and `f_min`/`f_max` follow the selected operand.
+impl<F> Float for Dual<F>
+where
+    F: Float,
+{
+    const PI: Dual<F> = Dual::constant_of(F::PI);
+    const EPSILON: Dual<F> = Dual::constant_of(F::EPSILON);
+    const INFINITY: Dual<F> = Dual::constant_of(F::INFINITY);
+    const NAN: Dual<F> = Dual::constant_of(F::NAN);
+
+    fn f_is_nan(self) -> bool {
+        self.value.f_is_nan() || self.deriv.f_is_nan()
+    }
+
+    fn f_is_finite(self) -> bool {
+        self.value.f_is_finite() && self.deriv.f_is_finite()
+    }
+
+    fn f_abs(self) -> Dual<F> {
+        if self.value < F::ZERO {
+            -self
+        } else {
+            self
+        }
+    }
+
+    fn f_copysign(self, sign: Dual<F>) -> Dual<F> {
+        let value = self.value.f_copysign(sign.value);
+        if F::ONE.f_copysign(value) == F::ONE.f_copysign(self.value) {
+            Dual::new(value, self.deriv)
+        } else {
+            Dual::new(value, -self.deriv)
+        }
+    }
+
+    fn f_min(self, other: Dual<F>) -> Dual<F> {
+        if self.value.f_min(other.value) == self.value {
+            self
+        } else {
+            other
+        }
+    }
+
+    fn f_max(self, other: Dual<F>) -> Dual<F> {
+        if self.value.f_max(other.value) == self.value {
+            self
+        } else {
+            other
+        }
+    }
+
+    fn f_floor(self) -> Dual<F> {
+        Dual::constant(self.value.f_floor())
+    }
+
+    fn f_ceil(self) -> Dual<F> {
+        Dual::constant(self.value.f_ceil())
+    }
+
+    fn f_mul_add(self, a: Dual<F>, b: Dual<F>) -> Dual<F> {
+        Dual::new(
+            self.value.f_mul_add(a.value, b.value),
+            self.deriv
+                .f_mul_add(a.value, self.value.f_mul_add(a.deriv, b.deriv)),
+        )
+    }
+
+    fn f_sqrt(self) -> Dual<F> {
+        let s = self.value.f_sqrt();
+        self.chain(s, F::f_from_f64(0.5) / s)
+    }
+
+    fn f_exp(self) -> Dual<F> {
+        self.exp()
+    }
+
+    fn f_ln(self) -> Dual<F> {
+        self.ln()
+    }
+
+    fn f_sin(self) -> Dual<F> {
+        self.sin()
+    }
+
+    fn f_cos(self) -> Dual<F> {
+        self.cos()
+    }
+
+    fn f_tan(self) -> Dual<F> {
+        self.tan()
+    }
+
+    fn f_atan(self) -> Dual<F> {
+        self.atan()
+    }
+
+    /// `∂/∂y atan2(y, x) = x / (x² + y²)` and `∂/∂x atan2(y, x) = -y / (x² + y²)`.
+    fn f_atan2(self, x: Dual<F>) -> Dual<F> {
+        if self.both_constant(x) {
+            return Dual::constant(self.value.f_atan2(x.value));
+        }
+        let r2 = x.value * x.value + self.value * self.value;
+        Dual::new(
+            self.value.f_atan2(x.value),
+            (x.value * self.deriv - self.value * x.deriv) / r2,
+        )
+    }
+
+    fn f_powi(self, n: i32) -> Dual<F> {
+        self.powi(n)
+    }
+
+    /// The `ln(self)` term of the derivative is skipped for a constant exponent, so negative
+    /// bases keep a finite derivative.
+    fn f_powf(self, p: Dual<F>) -> Dual<F> {
+        let r = self.powf(p.value);
+        if p.deriv == F::ZERO {
+            return r;
+        }
+        Dual::new(r.value, r.deriv + r.value * self.value.f_ln() * p.deriv)
+    }
+
+    fn f_from_f64(v: f64) -> Dual<F> {
+        Dual::constant(F::f_from_f64(v))
+    }
+
+    fn f_from_i32(v: i32) -> Dual<F> {
+        Dual::constant(F::f_from_i32(v))
+    }
+}
+
+impl<F> ApproxEq for Dual<F>
+where
+    F: ApproxEq,
+{
+    type Epsilon = F::Epsilon;
+
+    fn default_epsilon() -> F::Epsilon {
+        F::default_epsilon()
+    }
+
+    fn default_max_relative() -> F::Epsilon {
+        F::default_max_relative()
+    }
+
+    fn default_max_ulps() -> u32 {
+        F::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Dual<F>, epsilon: F::Epsilon) -> bool {
+        self.value.abs_diff_eq(&other.value, epsilon)
+            && self.deriv.abs_diff_eq(&other.deriv, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Dual<F>, epsilon: F::Epsilon, max_relative: F::Epsilon) -> bool {
+        self.value.relative_eq(&other.value, epsilon, max_relative)
+            && self.deriv.relative_eq(&other.deriv, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Dual<F>, epsilon: F::Epsilon, max_ulps: u32) -> bool {
+        self.value.ulps_eq(&other.value, epsilon, max_ulps)
+            && self.deriv.ulps_eq(&other.deriv, epsilon, max_ulps)
+    }
+}
+
+/// Seeds the `j`-th component of `x` as the variable and the rest as constants.
+fn seed<F, const N: usize>(x: Vector<F, N>, j: usize) -> Vector<Dual<F>, N>
+where
+    F: Float,
+{
+    Vector(std::array::from_fn(|i| {
+        if i == j {
+            Dual::variable(x[i])
+        } else {
+            Dual::constant(x[i])
+        }
+    }))
+}
+
+/// The gradient of a scalar function at `x`, using one forward pass per component.
+pub fn gradient<F, Func, const N: usize>(f: Func, x: Vector<F, N>) -> Vector<F, N>
+where
+    F: Float,
+    Func: Fn(Vector<Dual<F>, N>) -> Dual<F>,
+{
+    Vector(std::array::from_fn(|j| f(seed(x, j)).deriv))
+}
+
+/// The Jacobian of a vector function at `x`: entry `[i][j]` is `∂f_i / ∂x_j`.
+pub fn jacobian<F, Func, const M: usize, const N: usize>(
+    f: Func,
+    x: Vector<F, N>,
+) -> Matrix<F, M, N>
+where
+    F: Float,
+    Func: Fn(Vector<Dual<F>, N>) -> Vector<Dual<F>, M>,
+{
+    let columns: [Vector<Dual<F>, M>; N] = std::array::from_fn(|j| f(seed(x, j)));
+    Matrix(std::array::from_fn(|i| {
+        std::array::from_fn(|j| columns[j][i].deriv)
+    }))
+}
@@ -0,0 +1,310 @@
+use crate::approx::ApproxEq;
+use crate::float::{soft, Float};
+use crate::numeric::{Numeric, One, Zero};
+use crate::scalar::Conjugate;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// A binary fixed-point number: the integer `T` scaled by `2^-FRAC`.
+///
+/// Every operation is integer arithmetic, so results are bit-identical on every platform.
+/// Addition, subtraction and negation wrap on overflow like `wrapping_add`, while `f_abs`,
+/// `f_ceil` and `f_from_i32` saturate. Multiplication rounds to nearest with ties going up,
+/// and division truncates toward zero. Dividing by zero panics. The transcendental functions
+/// of [`Float`] evaluate [`soft`] in `f64` and round the result, so they are bit-identical too.
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<T, const FRAC: u32>(T);
+
+pub type Q16_16 = Fixed<i32, 16>;
+pub type Q32_32 = Fixed<i64, 32>;
+
+macro_rules! impl_fixed {
+    ($t: ty, $wide: ty, $uwide: ty) => {
+        impl<const FRAC: u32> Fixed<$t, FRAC> {
+            pub const ZERO: Self = Fixed(0);
+            pub const ONE: Self = Fixed(1 << FRAC);
+            /// The smallest positive value, `2^-FRAC`.
+            pub const EPSILON: Self = Fixed(1);
+            pub const MAX: Self = Fixed(<$t>::MAX);
+            pub const MIN: Self = Fixed(<$t>::MIN);
+
+            pub const fn from_bits(bits: $t) -> Self {
+                Fixed(bits)
+            }
+
+            pub const fn to_bits(self) -> $t {
+                self.0
+            }
+
+            pub const fn from_int(v: $t) -> Self {
+                Fixed(v << FRAC)
+            }
+
+            /// Rounds to the nearest representable value; out-of-range values saturate.
+            pub fn from_f64(v: f64) -> Self {
+                Fixed((v * 2f64.powi(FRAC as i32)).round() as $t)
+            }
+
+            pub fn to_f64(self) -> f64 {
+                self.0 as f64 / 2f64.powi(FRAC as i32)
+            }
+
+            /// Square root rounded to nearest, computed with integer operations only.
+            /// Negative values give zero.
+            pub fn sqrt(self) -> Self {
+                if self.0 <= 0 {
+                    return Self::ZERO;
+                }
+
+                let n = (self.0 as $uwide) << FRAC;
+                let mut rem = n;
+                let mut root: $uwide = 0;
+                let mut bit: $uwide = 1 << (<$uwide>::BITS - 2);
+                while bit > n {
+                    bit >>= 2;
+                }
+                while bit != 0 {
+                    if rem >= root + bit {
+                        rem -= root + bit;
+                        root = (root >> 1) + bit;
+                    } else {
+                        root >>= 1;
+                    }
+                    bit >>= 2;
+                }
+                if rem > root {
+                    root += 1;
+                }
+                Fixed(root as $t)
+            }
+        }
+
+        impl<const FRAC: u32> Add for Fixed<$t, FRAC> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Fixed(self.0.wrapping_add(rhs.0))
+            }
+        }
+
+        impl<const FRAC: u32> Sub for Fixed<$t, FRAC> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Fixed(self.0.wrapping_sub(rhs.0))
+            }
+        }
+
+        impl<const FRAC: u32> Mul for Fixed<$t, FRAC> {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                let p = self.0 as $wide * rhs.0 as $wide;
+                Fixed(((p + (1 << FRAC >> 1)) >> FRAC) as $t)
+            }
+        }
+
+        impl<const FRAC: u32> Div for Fixed<$t, FRAC> {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self {
+                Fixed((((self.0 as $wide) << FRAC) / rhs.0 as $wide) as $t)
+            }
+        }
+
+        impl<const FRAC: u32> Rem for Fixed<$t, FRAC> {
+            type Output = Self;
+
+            fn rem(self, rhs: Self) -> Self {
+                Fixed(self.0.wrapping_rem(rhs.0))
+            }
+        }
+
+        impl<const FRAC: u32> Neg for Fixed<$t, FRAC> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Fixed(self.0.wrapping_neg())
+            }
+        }
+
+        impl<const FRAC: u32> Sum for Fixed<$t, FRAC> {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, Add::add)
+            }
+        }
+
+        impl<const FRAC: u32> Product for Fixed<$t, FRAC> {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ONE, Mul::mul)
+            }
+        }
+
+        impl<const FRAC: u32> Zero for Fixed<$t, FRAC> {
+            const ZERO: Self = Self::ZERO;
+        }
+
+        impl<const FRAC: u32> One for Fixed<$t, FRAC> {
+            const ONE: Self = Self::ONE;
+        }
+
+        impl<const FRAC: u32> Numeric for Fixed<$t, FRAC> {}
+
+        impl<const FRAC: u32> Conjugate for Fixed<$t, FRAC> {
+            fn conj(self) -> Self {
+                self
+            }
+        }
+
+        impl_scalar_ops!([const FRAC: u32] Fixed<$t, FRAC>);
+
+        /// There is no infinity or NaN: `INFINITY` is `MAX` and `NAN` is zero, as `from_f64`
+        /// would give, and `f_is_nan` is always `false`.
+        impl<const FRAC: u32> Float for Fixed<$t, FRAC> {
+            const PI: Self = Fixed(
+                (std::f64::consts::PI * (1u128 << FRAC) as f64 + 0.5) as $t,
+            );
+            const EPSILON: Self = Fixed(1);
+            const INFINITY: Self = Self::MAX;
+            const NAN: Self = Self::ZERO;
+
+            fn f_is_nan(self) -> bool {
+                false
+            }
+
+            fn f_is_finite(self) -> bool {
+                true
+            }
+
+            fn f_abs(self) -> Self {
+                Fixed(self.0.saturating_abs())
+            }
+
+            fn f_copysign(self, sign: Self) -> Self {
+                if sign.0 < 0 {
+                    -self.f_abs()
+                } else {
+                    self.f_abs()
+                }
+            }
+
+            fn f_min(self, other: Self) -> Self {
+                self.min(other)
+            }
+
+            fn f_max(self, other: Self) -> Self {
+                self.max(other)
+            }
+
+            fn f_floor(self) -> Self {
+                Fixed(self.0 >> FRAC << FRAC)
+            }
+
+            fn f_ceil(self) -> Self {
+                match self.0.checked_add((1 << FRAC) - 1) {
+                    Some(v) => Fixed(v >> FRAC << FRAC),
+                    None => Self::MAX,
+                }
+            }
+
+            /// Rounds once, like `*`.
+            fn f_mul_add(self, a: Self, b: Self) -> Self {
+                let p = self.0 as $wide * a.0 as $wide + ((b.0 as $wide) << FRAC);
+                Fixed(((p + (1 << FRAC >> 1)) >> FRAC) as $t)
+            }
+
+            fn f_sqrt(self) -> Self {
+                self.sqrt()
+            }
+
+            fn f_exp(self) -> Self {
+                Self::from_f64(soft::exp(self.to_f64()))
+            }
+
+            fn f_ln(self) -> Self {
+                Self::from_f64(soft::log(self.to_f64()))
+            }
+
+            fn f_sin(self) -> Self {
+                Self::from_f64(soft::sin(self.to_f64()))
+            }
+
+            fn f_cos(self) -> Self {
+                Self::from_f64(soft::cos(self.to_f64()))
+            }
+
+            fn f_tan(self) -> Self {
+                Self::from_f64(soft::tan(self.to_f64()))
+            }
+
+            fn f_atan(self) -> Self {
+                Self::from_f64(soft::atan(self.to_f64()))
+            }
+
+            fn f_atan2(self, x: Self) -> Self {
+                Self::from_f64(soft::atan2(self.to_f64(), x.to_f64()))
+            }
+
+            fn f_powf(self, p: Self) -> Self {
+                Self::from_f64(soft::pow(self.to_f64(), p.to_f64()))
+            }
+
+            fn f_from_f64(v: f64) -> Self {
+                Self::from_f64(v)
+            }
+
+            fn f_from_i32(v: i32) -> Self {
+                // Exact in `f64`, so only the saturation of `from_f64` applies.
+                Self::from_f64(f64::from(v))
+            }
+        }
+
+        impl<const FRAC: u32> ApproxEq for Fixed<$t, FRAC> {
+            type Epsilon = Self;
+
+            fn default_epsilon() -> Self {
+                Fixed(1)
+            }
+
+            fn default_max_relative() -> Self {
+                Fixed(1)
+            }
+
+            fn default_max_ulps() -> u32 {
+                4
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self) -> bool {
+                self.0.abs_diff(other.0) <= epsilon.0.unsigned_abs()
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: Self, max_relative: Self) -> bool {
+                if self.abs_diff_eq(other, epsilon) {
+                    return true;
+                }
+                let largest = self.f_abs().max(other.f_abs());
+                self.0.abs_diff(other.0) <= (largest * max_relative).0.unsigned_abs()
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: Self, max_ulps: u32) -> bool {
+                self.abs_diff_eq(other, epsilon) || self.0.abs_diff(other.0) <= max_ulps.into()
+            }
+        }
+
+        impl<const FRAC: u32> fmt::Debug for Fixed<$t, FRAC> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.to_f64(), f)
+            }
+        }
+
+        impl<const FRAC: u32> fmt::Display for Fixed<$t, FRAC> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.to_f64(), f)
+            }
+        }
+    };
+}
+
+impl_fixed!(i32, i64, u64);
+impl_fixed!(i64, i128, u128);
@@ -0,0 +1,425 @@
+use crate::approx::ApproxEq;
+use crate::cast::Cast;
+use crate::float::{Float, Libm};
+use crate::numeric::{Numeric, One, Zero};
+use crate::scalar::Conjugate;
+use crate::vector::{Vector, V2};
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// An IEEE 754 binary16 value, as stored by GPU `half` formats.
+///
+/// Arithmetic is carried out in `f32` and rounded back to the nearest `f16`. `f32` has more than
+/// twice the precision of `f16`, so `+`, `-`, `*`, `/` and `f_sqrt` are correctly rounded.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Default)]
+pub struct f16(u16);
+
+impl f16 {
+    pub const ZERO: f16 = f16(0x0000);
+    pub const ONE: f16 = f16(0x3c00);
+    pub const EPSILON: f16 = f16(0x1400);
+    pub const MAX: f16 = f16(0x7bff);
+    pub const MIN: f16 = f16(0xfbff);
+    pub const MIN_POSITIVE: f16 = f16(0x0400);
+    pub const INFINITY: f16 = f16(0x7c00);
+    pub const NEG_INFINITY: f16 = f16(0xfc00);
+    pub const NAN: f16 = f16(0x7e00);
+
+    pub const fn from_bits(bits: u16) -> f16 {
+        f16(bits)
+    }
+
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Rounds to the nearest `f16`, ties to even.
+    pub fn from_f32(v: f32) -> f16 {
+        f16::from_f64(f64::from(v))
+    }
+
+    /// Rounds to the nearest `f16`, ties to even.
+    pub fn from_f64(v: f64) -> f16 {
+        let bits = v.to_bits();
+        let sign = ((bits >> 48) & 0x8000) as u16;
+        let exp = ((bits >> 52) & 0x7ff) as i64;
+        let man = bits & ((1 << 52) - 1);
+
+        if exp == 0x7ff {
+            return f16(sign | if man == 0 { 0x7c00 } else { 0x7e00 });
+        }
+        let e = exp - 1023;
+        if e > 15 {
+            return f16(sign | 0x7c00);
+        }
+
+        // The significand with its implicit bit, the number of bits to drop to reach the f16
+        // precision, and the bits the result starts from before rounding.
+        let (sig, shift, base) = if e >= -14 {
+            (man, 42, ((e + 15) as u64) << 10)
+        } else {
+            (man | (1 << 52), 28 - e, 0)
+        };
+        if shift >= 64 {
+            return f16(sign);
+        }
+        let rem = sig & ((1 << shift) - 1);
+        let half = 1 << (shift - 1);
+        let mut h = base + (sig >> shift);
+        if rem > half || (rem == half && h & 1 == 1) {
+            // A carry out of the mantissa correctly bumps the exponent, up to infinity.
+            h += 1;
+        }
+        f16(sign | h as u16)
+    }
+
+    /// Exact conversion.
+    pub fn to_f32(self) -> f32 {
+        let sign = u32::from(self.0 & 0x8000) << 16;
+        let exp = u32::from(self.0 >> 10) & 0x1f;
+        let man = u32::from(self.0) & 0x3ff;
+
+        match exp {
+            0 => {
+                let v = man as f32 * (1.0 / (1 << 24) as f32);
+                if sign == 0 {
+                    v
+                } else {
+                    -v
+                }
+            }
+            0x1f => f32::from_bits(sign | 0x7f80_0000 | (man << 13)),
+            _ => f32::from_bits(sign | ((exp + 112) << 23) | (man << 13)),
+        }
+    }
+
+    /// Exact conversion.
+    pub fn to_f64(self) -> f64 {
+        f64::from(self.to_f32())
+    }
+
+    pub fn is_nan(self) -> bool {
+        self.0 & 0x7fff > 0x7c00
+    }
+
+    pub fn is_infinite(self) -> bool {
+        self.0 & 0x7fff == 0x7c00
+    }
+
+    pub fn is_finite(self) -> bool {
+        self.0 & 0x7c00 != 0x7c00
+    }
+
+    pub fn is_sign_positive(self) -> bool {
+        self.0 & 0x8000 == 0
+    }
+}
+
+impl From<f16> for f32 {
+    fn from(v: f16) -> f32 {
+        v.to_f32()
+    }
+}
+
+impl From<f16> for f64 {
+    fn from(v: f16) -> f64 {
+        v.to_f64()
+    }
+}
+
+impl PartialEq for f16 {
+    fn eq(&self, other: &f16) -> bool {
+        self.to_f32() == other.to_f32()
+    }
+}
+
+impl PartialOrd for f16 {
+    fn partial_cmp(&self, other: &f16) -> Option<Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
+    }
+}
+
+impl fmt::Debug for f16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_f32(), f)
+    }
+}
+
+impl fmt::Display for f16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f32(), f)
+    }
+}
+
+macro_rules! impl_binop {
+    ($op: ident, $f: ident) => {
+        impl $op for f16 {
+            type Output = f16;
+
+            fn $f(self, rhs: f16) -> f16 {
+                f16::from_f32(self.to_f32().$f(rhs.to_f32()))
+            }
+        }
+    };
+}
+
+impl_binop!(Add, add);
+impl_binop!(Sub, sub);
+impl_binop!(Mul, mul);
+impl_binop!(Div, div);
+impl_binop!(Rem, rem);
+
+impl Neg for f16 {
+    type Output = f16;
+
+    fn neg(self) -> f16 {
+        f16(self.0 ^ 0x8000)
+    }
+}
+
+impl Sum for f16 {
+    fn sum<I: Iterator<Item = f16>>(iter: I) -> f16 {
+        iter.fold(f16::ZERO, Add::add)
+    }
+}
+
+impl Product for f16 {
+    fn product<I: Iterator<Item = f16>>(iter: I) -> f16 {
+        iter.fold(f16::ONE, Mul::mul)
+    }
+}
+
+impl Zero for f16 {
+    const ZERO: f16 = f16::ZERO;
+}
+
+impl One for f16 {
+    const ONE: f16 = f16::ONE;
+}
+
+impl Numeric for f16 {}
+
+impl Conjugate for f16 {
+    fn conj(self) -> f16 {
+        self
+    }
+}
+
+impl_scalar_ops!(f16);
+
+/// The transcendental functions round the `f32` result, so they can be off by one `f16` ulp in
+/// rare double-rounding cases.
+impl Float for f16 {
+    const PI: f16 = f16(0x4248);
+    const EPSILON: f16 = f16::EPSILON;
+    const INFINITY: f16 = f16::INFINITY;
+    const NAN: f16 = f16::NAN;
+
+    fn f_is_nan(self) -> bool {
+        self.is_nan()
+    }
+
+    fn f_is_finite(self) -> bool {
+        self.is_finite()
+    }
+
+    fn f_abs(self) -> f16 {
+        f16(self.0 & 0x7fff)
+    }
+
+    fn f_copysign(self, sign: f16) -> f16 {
+        f16(self.0 & 0x7fff | sign.0 & 0x8000)
+    }
+
+    fn f_floor(self) -> f16 {
+        f16::from_f32(self.to_f32().floor())
+    }
+
+    fn f_ceil(self) -> f16 {
+        f16::from_f32(self.to_f32().ceil())
+    }
+
+    /// The product and sum are exact in `f64`, so the result is rounded once.
+    fn f_mul_add(self, a: f16, b: f16) -> f16 {
+        f16::from_f64(self.to_f64() * a.to_f64() + b.to_f64())
+    }
+
+    fn f_sqrt(self) -> f16 {
+        f16::from_f32(self.to_f32().sqrt())
+    }
+
+    fn f_exp(self) -> f16 {
+        f16::from_f32(self.to_f32().l_exp())
+    }
+
+    fn f_ln(self) -> f16 {
+        f16::from_f32(self.to_f32().l_ln())
+    }
+
+    fn f_sin(self) -> f16 {
+        f16::from_f32(self.to_f32().l_sin())
+    }
+
+    fn f_cos(self) -> f16 {
+        f16::from_f32(self.to_f32().l_cos())
+    }
+
+    fn f_tan(self) -> f16 {
+        f16::from_f32(self.to_f32().l_tan())
+    }
+
+    fn f_atan(self) -> f16 {
+        f16::from_f32(self.to_f32().l_atan())
+    }
+
+    fn f_atan2(self, x: f16) -> f16 {
+        f16::from_f32(self.to_f32().l_atan2(x.to_f32()))
+    }
+
+    fn f_powi(self, n: i32) -> f16 {
+        f16::from_f32(self.to_f32().l_powi(n))
+    }
+
+    fn f_powf(self, p: f16) -> f16 {
+        f16::from_f32(self.to_f32().l_powf(p.to_f32()))
+    }
+
+    fn f_from_f64(v: f64) -> f16 {
+        f16::from_f64(v)
+    }
+
+    fn f_from_i32(v: i32) -> f16 {
+        f16::from_f64(f64::from(v))
+    }
+}
+
+impl ApproxEq for f16 {
+    type Epsilon = f16;
+
+    fn default_epsilon() -> f16 {
+        f16::EPSILON
+    }
+
+    fn default_max_relative() -> f16 {
+        f16::EPSILON
+    }
+
+    fn default_max_ulps() -> u32 {
+        4
+    }
+
+    fn abs_diff_eq(&self, other: &f16, epsilon: f16) -> bool {
+        self.to_f32().abs_diff_eq(&other.to_f32(), epsilon.to_f32())
+    }
+
+    fn relative_eq(&self, other: &f16, epsilon: f16, max_relative: f16) -> bool {
+        self.to_f32()
+            .relative_eq(&other.to_f32(), epsilon.to_f32(), max_relative.to_f32())
+    }
+
+    fn ulps_eq(&self, other: &f16, epsilon: f16, max_ulps: u32) -> bool {
+        if self.is_nan() || other.is_nan() {
+            return false;
+        }
+        if self.abs_diff_eq(other, epsilon) {
+            return true;
+        }
+        if self.is_sign_positive() != other.is_sign_positive() {
+            return false;
+        }
+
+        let (a, b) = (self.0 as i16, other.0 as i16);
+        u32::from(a.wrapping_sub(b).unsigned_abs()) <= max_ulps
+    }
+}
+
+macro_rules! impl_cast_half {
+    ($t: ty, $from: ident, $to: ident) => {
+        impl Cast<f16> for $t {
+            fn cast(self) -> f16 {
+                f16::$from(self)
+            }
+
+            fn saturating_cast(self) -> f16 {
+                let v = f16::$from(self);
+                if v.is_infinite() && self.is_finite() {
+                    if self > 0.0 {
+                        f16::MAX
+                    } else {
+                        f16::MIN
+                    }
+                } else {
+                    v
+                }
+            }
+
+            fn checked_cast(self) -> Option<f16> {
+                let v = f16::$from(self);
+                if v.is_infinite() && self.is_finite() {
+                    None
+                } else {
+                    Some(v)
+                }
+            }
+        }
+
+        impl Cast<$t> for f16 {
+            fn cast(self) -> $t {
+                self.$to()
+            }
+
+            fn saturating_cast(self) -> $t {
+                self.$to()
+            }
+
+            fn checked_cast(self) -> Option<$t> {
+                Some(self.$to())
+            }
+        }
+    };
+}
+
+impl_cast_half!(f32, from_f32, to_f32);
+impl_cast_half!(f64, from_f64, to_f64);
+
+impl Cast<f16> for f16 {
+    fn cast(self) -> f16 {
+        self
+    }
+
+    fn saturating_cast(self) -> f16 {
+        self
+    }
+
+    fn checked_cast(self) -> Option<f16> {
+        Some(self)
+    }
+}
+
+impl<const N: usize> From<Vector<f16, N>> for Vector<f32, N> {
+    fn from(v: Vector<f16, N>) -> Self {
+        v.map(f16::to_f32)
+    }
+}
+
+impl<const N: usize> From<Vector<f16, N>> for Vector<f64, N> {
+    fn from(v: Vector<f16, N>) -> Self {
+        v.map(f16::to_f64)
+    }
+}
+
+/// GLSL's `packHalf2x16`: `v.x` goes to the least significant 16 bits.
+pub fn pack_half_2x16(v: V2<f32>) -> u32 {
+    u32::from(f16::from_f32(v[0]).to_bits()) | u32::from(f16::from_f32(v[1]).to_bits()) << 16
+}
+
+/// GLSL's `unpackHalf2x16`, the inverse of [`pack_half_2x16`].
+pub fn unpack_half_2x16(v: u32) -> V2<f32> {
+    V2([
+        f16::from_bits(v as u16).to_f32(),
+        f16::from_bits((v >> 16) as u16).to_f32(),
+    ])
+}
@@ -0,0 +1,593 @@
+use crate::approx::ApproxEq;
+use crate::float::Float;
+use crate::numeric::{Numeric, One, Zero};
+use crate::scalar::Conjugate;
+use std::cmp::Ordering;
+use std::iter::{Product, Sum};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// Floating point types that can bound an [`Interval`].
+pub trait Bound: Float {
+    /// The neighbours of π: `PI_DOWN < π < PI_UP`.
+    const PI_DOWN: Self;
+    const PI_UP: Self;
+
+    fn next_up(self) -> Self;
+    fn next_down(self) -> Self;
+    /// The tightest `(lo, hi)` with `lo <= v <= hi`.
+    fn enclose_f64(v: f64) -> (Self, Self);
+}
+
+impl Bound for f32 {
+    const PI_DOWN: f32 = f32::from_bits(std::f32::consts::PI.to_bits() - 1);
+    const PI_UP: f32 = std::f32::consts::PI;
+
+    fn next_up(self) -> f32 {
+        f32::next_up(self)
+    }
+
+    fn next_down(self) -> f32 {
+        f32::next_down(self)
+    }
+
+    fn enclose_f64(v: f64) -> (f32, f32) {
+        let r = v as f32;
+        match f64::from(r).partial_cmp(&v) {
+            Some(Ordering::Less) => (r, r.next_up()),
+            Some(Ordering::Greater) => (r.next_down(), r),
+            _ => (r, r),
+        }
+    }
+}
+
+impl Bound for f64 {
+    const PI_DOWN: f64 = std::f64::consts::PI;
+    const PI_UP: f64 = f64::from_bits(std::f64::consts::PI.to_bits() + 1);
+
+    fn next_up(self) -> f64 {
+        f64::next_up(self)
+    }
+
+    fn next_down(self) -> f64 {
+        f64::next_down(self)
+    }
+
+    fn enclose_f64(v: f64) -> (f64, f64) {
+        (v, v)
+    }
+}
+
+/// A closed interval `[lo, hi]` that is guaranteed to contain the exact result.
+///
+/// Every operation rounds its lower bound down and its upper bound up, so a chain of
+/// operations encloses the value the same chain would have in exact arithmetic. Division by an
+/// interval containing zero gives `[-inf, inf]`.
+///
+/// `a < b` holds only if every value of `a` is below every value of `b`; intervals that overlap
+/// are unordered unless they are identical.
+#[derive(Copy, Clone, Default, PartialEq, Debug)]
+pub struct Interval<F> {
+    lo: F,
+    hi: F,
+}
+
+fn min<F: PartialOrd>(a: F, b: F) -> F {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn max<F: PartialOrd>(a: F, b: F) -> F {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+impl<F> Interval<F>
+where
+    F: Bound,
+{
+    /// # Panics
+    ///
+    /// Panics if `lo > hi` or either bound is `NaN`.
+    pub fn new(lo: F, hi: F) -> Interval<F> {
+        assert!(lo <= hi, "interval bounds out of order");
+        Interval { lo, hi }
+    }
+
+    pub fn point(v: F) -> Interval<F> {
+        Interval { lo: v, hi: v }
+    }
+
+    pub fn entire() -> Interval<F> {
+        Interval {
+            lo: -F::INFINITY,
+            hi: F::INFINITY,
+        }
+    }
+
+    /// Rounds `lo` down and `hi` up.
+    fn outward(lo: F, hi: F) -> Interval<F> {
+        Interval {
+            lo: lo.next_down(),
+            hi: hi.next_up(),
+        }
+    }
+
+    /// The outward-rounded hull of the endpoint results of `a op b`. A NaN endpoint from
+    /// non-NaN operands, such as `inf / inf`, gives `entire` so the result stays an enclosure.
+    fn enclose_bounds(a: Interval<F>, b: Interval<F>, p: [F; 4]) -> Interval<F> {
+        if a.f_is_nan() || b.f_is_nan() {
+            return Interval::NAN;
+        }
+        if p.iter().any(|v| v.f_is_nan()) {
+            return Interval::entire();
+        }
+        Interval::outward(
+            min(min(p[0], p[1]), min(p[2], p[3])),
+            max(max(p[0], p[1]), max(p[2], p[3])),
+        )
+    }
+
+    /// Widens bounds computed by the transcendental functions of `F`, which are assumed to be
+    /// within one ulp of the exact value.
+    fn widen(lo: F, hi: F) -> Interval<F> {
+        Interval::outward(lo.next_down(), hi.next_up())
+    }
+
+    /// The range of `sin` or `cos` between the endpoint values, extended to `±1` where the
+    /// interval contains an extremum `(n + offset) · π`: a maximum for even `n` and a minimum for
+    /// odd `n`.
+    fn periodic(self, f: fn(F) -> F, offset: F) -> Interval<F> {
+        if self.f_is_nan() {
+            return Interval::NAN;
+        }
+        let one = F::ONE;
+        let unit = Interval { lo: -one, hi: one };
+        // Every integer in `[first, last]` may be such an `n`; extra candidates only widen the
+        // result.
+        let q = self / Interval::PI - Interval::point(offset);
+        let (first, last) = (q.lo.f_ceil(), q.hi.f_floor());
+        if !q.f_is_finite() || q.mag() >= one / F::EPSILON || last - first >= one {
+            return unit;
+        }
+        let (a, b) = (f(self.lo), f(self.hi));
+        let mut r = Interval::widen(min(a, b), max(a, b));
+        if first == last {
+            let half = first * F::f_from_f64(0.5);
+            if half.f_floor() == half {
+                r.hi = one;
+            } else {
+                r.lo = -one;
+            }
+        }
+        r.clamp(one)
+    }
+
+    /// Clamps both bounds to `[-m, m]`.
+    fn clamp(self, m: F) -> Interval<F> {
+        Interval {
+            lo: max(self.lo, -m),
+            hi: min(self.hi, m),
+        }
+    }
+
+    pub fn lo(self) -> F {
+        self.lo
+    }
+
+    pub fn hi(self) -> F {
+        self.hi
+    }
+
+    pub fn width(self) -> F {
+        (self.hi - self.lo).next_up()
+    }
+
+    pub fn contains(self, v: F) -> bool {
+        self.lo <= v && v <= self.hi
+    }
+
+    /// The largest absolute value in the interval.
+    pub fn mag(self) -> F {
+        max(self.lo.f_abs(), self.hi.f_abs())
+    }
+
+    /// The smallest interval containing both.
+    pub fn hull(self, other: Interval<F>) -> Interval<F> {
+        Interval {
+            lo: min(self.lo, other.lo),
+            hi: max(self.hi, other.hi),
+        }
+    }
+}
+
+impl<F> Add for Interval<F>
+where
+    F: Bound,
+{
+    type Output = Interval<F>;
+
+    fn add(self, rhs: Interval<F>) -> Interval<F> {
+        Interval::outward(self.lo + rhs.lo, self.hi + rhs.hi)
+    }
+}
+
+impl<F> Sub for Interval<F>
+where
+    F: Bound,
+{
+    type Output = Interval<F>;
+
+    fn sub(self, rhs: Interval<F>) -> Interval<F> {
+        Interval::outward(self.lo - rhs.hi, self.hi - rhs.lo)
+    }
+}
+
+impl<F> Mul for Interval<F>
+where
+    F: Bound,
+{
+    type Output = Interval<F>;
+
+    fn mul(self, rhs: Interval<F>) -> Interval<F> {
+        // A bound of zero stands for the real number zero, so `0 * ±inf` is `0`, not NaN.
+        let mul = |a: F, b: F| {
+            if a == F::ZERO || b == F::ZERO {
+                F::ZERO
+            } else {
+                a * b
+            }
+        };
+        Interval::enclose_bounds(
+            self,
+            rhs,
+            [
+                mul(self.lo, rhs.lo),
+                mul(self.lo, rhs.hi),
+                mul(self.hi, rhs.lo),
+                mul(self.hi, rhs.hi),
+            ],
+        )
+    }
+}
+
+impl<F> Div for Interval<F>
+where
+    F: Bound,
+{
+    type Output = Interval<F>;
+
+    fn div(self, rhs: Interval<F>) -> Interval<F> {
+        if rhs.contains(F::default()) {
+            return Interval::entire();
+        }
+        Interval::enclose_bounds(
+            self,
+            rhs,
+            [
+                self.lo / rhs.lo,
+                self.lo / rhs.hi,
+                self.hi / rhs.lo,
+                self.hi / rhs.hi,
+            ],
+        )
+    }
+}
+
+impl<F> Rem for Interval<F>
+where
+    F: Bound,
+{
+    type Output = Interval<F>;
+
+    /// Bounds `a % b` by `|a % b| <= min(|a|, |b|)` and the sign of `a`.
+    fn rem(self, rhs: Interval<F>) -> Interval<F> {
+        let zero = F::default();
+        if rhs.contains(zero) {
+            return Interval::entire();
+        }
+        let m = rhs.mag();
+        Interval {
+            lo: if self.lo >= zero {
+                zero
+            } else {
+                max(self.lo, -m)
+            },
+            hi: if self.hi <= zero {
+                zero
+            } else {
+                min(self.hi, m)
+            },
+        }
+    }
+}
+
+impl<F> Neg for Interval<F>
+where
+    F: Bound,
+{
+    type Output = Interval<F>;
+
+    fn neg(self) -> Interval<F> {
+        Interval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl<F> PartialOrd for Interval<F>
+where
+    F: Bound,
+{
+    fn partial_cmp(&self, other: &Interval<F>) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else if self.hi < other.lo {
+            Some(Ordering::Less)
+        } else if self.lo > other.hi {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+impl<F> Sum for Interval<F>
+where
+    F: Bound,
+{
+    fn sum<I: Iterator<Item = Interval<F>>>(iter: I) -> Interval<F> {
+        iter.fold(Interval::default(), Add::add)
+    }
+}
+
+impl<F> Product for Interval<F>
+where
+    F: Bound,
+{
+    fn product<I: Iterator<Item = Interval<F>>>(iter: I) -> Interval<F> {
+        iter.fold(Interval::ONE, Mul::mul)
+    }
+}
+
+impl<F> Zero for Interval<F>
+where
+    F: Bound,
+{
+    const ZERO: Interval<F> = Interval {
+        lo: F::ZERO,
+        hi: F::ZERO,
+    };
+}
+
+impl<F> One for Interval<F>
+where
+    F: Bound,
+{
+    const ONE: Interval<F> = Interval {
+        lo: F::ONE,
+        hi: F::ONE,
+    };
+}
+
+impl<F> Numeric for Interval<F> where F: Bound {}
+
+impl<F> Conjugate for Interval<F>
+where
+    F: Bound,
+{
+    fn conj(self) -> Interval<F> {
+        self
+    }
+}
+
+impl_scalar_ops!([F: Bound] Interval<F>);
+
+impl<F> Float for Interval<F>
+where
+    F: Bound,
+{
+    /// Encloses π.
+    const PI: Interval<F> = Interval {
+        lo: F::PI_DOWN,
+        hi: F::PI_UP,
+    };
+    const EPSILON: Interval<F> = Interval {
+        lo: F::EPSILON,
+        hi: F::EPSILON,
+    };
+    const INFINITY: Interval<F> = Interval {
+        lo: F::INFINITY,
+        hi: F::INFINITY,
+    };
+    const NAN: Interval<F> = Interval {
+        lo: F::NAN,
+        hi: F::NAN,
+    };
+
+    fn f_is_nan(self) -> bool {
+        self.lo.f_is_nan() || self.hi.f_is_nan()
+    }
+
+    fn f_is_finite(self) -> bool {
+        self.lo.f_is_finite() && self.hi.f_is_finite()
+    }
+
+    fn f_abs(self) -> Interval<F> {
+        let zero = F::ZERO;
+        if self.lo >= zero {
+            self
+        } else if self.hi <= zero {
+            -self
+        } else {
+            Interval {
+                lo: zero,
+                hi: self.mag(),
+            }
+        }
+    }
+
+    /// Takes the hull of both signs when `sign` contains values of either sign.
+    fn f_copysign(self, sign: Interval<F>) -> Interval<F> {
+        let positive = |v: F| F::ONE.f_copysign(v) > F::ZERO;
+        let a = self.f_abs();
+        if positive(sign.lo) {
+            a
+        } else if !positive(sign.hi) {
+            -a
+        } else {
+            a.hull(-a)
+        }
+    }
+
+    fn f_min(self, other: Interval<F>) -> Interval<F> {
+        Interval {
+            lo: self.lo.f_min(other.lo),
+            hi: self.hi.f_min(other.hi),
+        }
+    }
+
+    fn f_max(self, other: Interval<F>) -> Interval<F> {
+        Interval {
+            lo: self.lo.f_max(other.lo),
+            hi: self.hi.f_max(other.hi),
+        }
+    }
+
+    fn f_floor(self) -> Interval<F> {
+        Interval {
+            lo: self.lo.f_floor(),
+            hi: self.hi.f_floor(),
+        }
+    }
+
+    fn f_ceil(self) -> Interval<F> {
+        Interval {
+            lo: self.lo.f_ceil(),
+            hi: self.hi.f_ceil(),
+        }
+    }
+
+    /// Negative parts of the interval are ignored; an entirely negative interval gives `NaN`
+    /// bounds.
+    fn f_sqrt(self) -> Interval<F> {
+        let zero = F::ZERO;
+        if self.hi < zero {
+            return Interval::NAN;
+        }
+        let lo = if self.lo > zero {
+            max(self.lo.f_sqrt().next_down(), zero)
+        } else {
+            zero
+        };
+        Interval {
+            lo,
+            hi: self.hi.f_sqrt().next_up(),
+        }
+    }
+
+    fn f_exp(self) -> Interval<F> {
+        let r = Interval::widen(self.lo.f_exp(), self.hi.f_exp());
+        Interval {
+            lo: max(r.lo, F::ZERO),
+            hi: r.hi,
+        }
+    }
+
+    /// Negative parts of the interval are ignored, like [`f_sqrt`](Self::f_sqrt).
+    fn f_ln(self) -> Interval<F> {
+        let zero = F::ZERO;
+        if self.hi < zero {
+            return Interval::NAN;
+        }
+        let lo = if self.lo > zero {
+            self.lo.f_ln()
+        } else {
+            -F::INFINITY
+        };
+        Interval::widen(lo, self.hi.f_ln())
+    }
+
+    fn f_sin(self) -> Interval<F> {
+        self.periodic(F::f_sin, F::f_from_f64(0.5))
+    }
+
+    fn f_cos(self) -> Interval<F> {
+        self.periodic(F::f_cos, F::ZERO)
+    }
+
+    /// The extremes lie at the corners unless the box touches the negative `x` axis, where the
+    /// angle jumps between `-π` and `π`.
+    fn f_atan2(self, x: Interval<F>) -> Interval<F> {
+        if self.f_is_nan() || x.f_is_nan() {
+            return Interval::NAN;
+        }
+        let zero = F::ZERO;
+        if x.lo < zero && self.lo <= zero && self.hi >= zero {
+            return Interval {
+                lo: -F::PI_UP,
+                hi: F::PI_UP,
+            };
+        }
+        let c = [
+            self.lo.f_atan2(x.lo),
+            self.lo.f_atan2(x.hi),
+            self.hi.f_atan2(x.lo),
+            self.hi.f_atan2(x.hi),
+        ];
+        Interval::widen(
+            min(min(c[0], c[1]), min(c[2], c[3])),
+            max(max(c[0], c[1]), max(c[2], c[3])),
+        )
+        .clamp(F::PI_UP)
+    }
+
+    fn f_from_f64(v: f64) -> Interval<F> {
+        let (lo, hi) = F::enclose_f64(v);
+        Interval { lo, hi }
+    }
+}
+
+impl<F> ApproxEq for Interval<F>
+where
+    F: ApproxEq,
+{
+    type Epsilon = F::Epsilon;
+
+    fn default_epsilon() -> F::Epsilon {
+        F::default_epsilon()
+    }
+
+    fn default_max_relative() -> F::Epsilon {
+        F::default_max_relative()
+    }
+
+    fn default_max_ulps() -> u32 {
+        F::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Interval<F>, epsilon: F::Epsilon) -> bool {
+        self.lo.abs_diff_eq(&other.lo, epsilon) && self.hi.abs_diff_eq(&other.hi, epsilon)
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Interval<F>,
+        epsilon: F::Epsilon,
+        max_relative: F::Epsilon,
+    ) -> bool {
+        self.lo.relative_eq(&other.lo, epsilon, max_relative)
+            && self.hi.relative_eq(&other.hi, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Interval<F>, epsilon: F::Epsilon, max_ulps: u32) -> bool {
+        self.lo.ulps_eq(&other.lo, epsilon, max_ulps)
+            && self.hi.ulps_eq(&other.hi, epsilon, max_ulps)
+    }
+}
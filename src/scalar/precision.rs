@@ -0,0 +1,295 @@
+use crate::approx::ApproxEq;
+use crate::cast::Cast;
+use crate::float::{Float, Libm};
+use crate::numeric::{Numeric, One, Zero};
+use crate::scalar::{f16, Conjugate};
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// `highp` is IEEE binary32 on every GLSL ES implementation.
+pub type Highp = f32;
+
+/// A `mediump` float: every result is rounded to the nearest `f16`, so values beyond `65504`
+/// overflow to infinity and small values flush through the `f16` subnormals.
+#[derive(Copy, Clone, Default, PartialEq, PartialOrd)]
+pub struct Mediump(f32);
+
+/// A `lowp` float: every result is rounded to a multiple of `2^-8` and saturated to the 10-bit
+/// two's complement range `[-2, 2 - 2^-8]`.
+#[derive(Copy, Clone, Default, PartialEq, PartialOrd)]
+pub struct Lowp(f32);
+
+// The rounding takes `f64` so that `f_from_f64` rounds once, not through `f32` first.
+fn round_mediump(v: f64) -> f32 {
+    f16::from_f64(v).to_f32()
+}
+
+fn round_lowp(v: f64) -> f32 {
+    if v.is_nan() {
+        return 0.0;
+    }
+    // Ties go to even, matching the `f16` rounding behind `Mediump`.
+    ((v * 256.0).round_ties_even().clamp(-512.0, 511.0) / 256.0) as f32
+}
+
+macro_rules! impl_precision {
+    ($t: ident, $round: ident, $epsilon: expr, $pi: expr, $infinity: expr, $nan: expr) => {
+        impl $t {
+            pub const EPSILON: $t = $t($epsilon);
+
+            /// Rounds `v` to this precision.
+            pub fn new(v: f32) -> $t {
+                $t($round(f64::from(v)))
+            }
+
+            pub fn to_f32(self) -> f32 {
+                self.0
+            }
+        }
+
+        impl From<f32> for $t {
+            fn from(v: f32) -> $t {
+                $t::new(v)
+            }
+        }
+
+        impl From<$t> for f32 {
+            fn from(v: $t) -> f32 {
+                v.0
+            }
+        }
+
+        impl fmt::Debug for $t {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl_precision!(@binop $t, Add, add);
+        impl_precision!(@binop $t, Sub, sub);
+        impl_precision!(@binop $t, Mul, mul);
+        impl_precision!(@binop $t, Div, div);
+        impl_precision!(@binop $t, Rem, rem);
+
+        impl Neg for $t {
+            type Output = $t;
+
+            fn neg(self) -> $t {
+                $t::new(-self.0)
+            }
+        }
+
+        impl Sum for $t {
+            fn sum<I: Iterator<Item = $t>>(iter: I) -> $t {
+                iter.fold($t::default(), Add::add)
+            }
+        }
+
+        impl Product for $t {
+            fn product<I: Iterator<Item = $t>>(iter: I) -> $t {
+                iter.fold($t::new(1.0), Mul::mul)
+            }
+        }
+
+        impl Zero for $t {
+            const ZERO: $t = $t(0.0);
+        }
+
+        impl One for $t {
+            const ONE: $t = $t(1.0);
+        }
+
+        impl Numeric for $t {}
+
+        impl Conjugate for $t {
+            fn conj(self) -> $t {
+                self
+            }
+        }
+
+        impl_scalar_ops!($t);
+
+        impl Float for $t {
+            const PI: $t = $t($pi);
+            const EPSILON: $t = $t::EPSILON;
+            const INFINITY: $t = $t($infinity);
+            const NAN: $t = $t($nan);
+
+            fn f_is_nan(self) -> bool {
+                self.0.is_nan()
+            }
+
+            fn f_is_finite(self) -> bool {
+                self.0.is_finite()
+            }
+
+            fn f_abs(self) -> $t {
+                $t(self.0.abs())
+            }
+
+            fn f_copysign(self, sign: $t) -> $t {
+                $t(self.0.copysign(sign.0))
+            }
+
+            fn f_min(self, other: $t) -> $t {
+                $t(self.0.min(other.0))
+            }
+
+            fn f_max(self, other: $t) -> $t {
+                $t(self.0.max(other.0))
+            }
+
+            fn f_floor(self) -> $t {
+                $t::new(self.0.floor())
+            }
+
+            fn f_ceil(self) -> $t {
+                $t::new(self.0.ceil())
+            }
+
+            fn f_mul_add(self, a: $t, b: $t) -> $t {
+                $t::new(self.0.mul_add(a.0, b.0))
+            }
+
+            fn f_sqrt(self) -> $t {
+                $t::new(self.0.sqrt())
+            }
+
+            fn f_exp(self) -> $t {
+                $t::new(self.0.l_exp())
+            }
+
+            fn f_ln(self) -> $t {
+                $t::new(self.0.l_ln())
+            }
+
+            fn f_sin(self) -> $t {
+                $t::new(self.0.l_sin())
+            }
+
+            fn f_cos(self) -> $t {
+                $t::new(self.0.l_cos())
+            }
+
+            fn f_tan(self) -> $t {
+                $t::new(self.0.l_tan())
+            }
+
+            fn f_atan(self) -> $t {
+                $t::new(self.0.l_atan())
+            }
+
+            fn f_atan2(self, x: $t) -> $t {
+                $t::new(self.0.l_atan2(x.0))
+            }
+
+            fn f_powi(self, n: i32) -> $t {
+                $t::new(self.0.l_powi(n))
+            }
+
+            fn f_powf(self, p: $t) -> $t {
+                $t::new(self.0.l_powf(p.0))
+            }
+
+            fn f_from_f64(v: f64) -> $t {
+                $t($round(v))
+            }
+
+            fn f_from_i32(v: i32) -> $t {
+                $t($round(f64::from(v)))
+            }
+        }
+
+        impl ApproxEq for $t {
+            type Epsilon = $t;
+
+            fn default_epsilon() -> $t {
+                $t::EPSILON
+            }
+
+            fn default_max_relative() -> $t {
+                $t::EPSILON
+            }
+
+            fn default_max_ulps() -> u32 {
+                4
+            }
+
+            fn abs_diff_eq(&self, other: &$t, epsilon: $t) -> bool {
+                self.0.abs_diff_eq(&other.0, epsilon.0)
+            }
+
+            fn relative_eq(&self, other: &$t, epsilon: $t, max_relative: $t) -> bool {
+                self.0.relative_eq(&other.0, epsilon.0, max_relative.0)
+            }
+
+            /// Counts steps of [`EPSILON`](Self::EPSILON) relative to the larger magnitude.
+            fn ulps_eq(&self, other: &$t, epsilon: $t, max_ulps: u32) -> bool {
+                self.relative_eq(other, epsilon, $t($t::EPSILON.0 * max_ulps as f32))
+            }
+        }
+
+        impl Cast<$t> for f32 {
+            fn cast(self) -> $t {
+                $t::new(self)
+            }
+
+            fn saturating_cast(self) -> $t {
+                $t::new(self)
+            }
+
+            fn checked_cast(self) -> Option<$t> {
+                Some($t::new(self))
+            }
+        }
+
+        impl Cast<f32> for $t {
+            fn cast(self) -> f32 {
+                self.0
+            }
+
+            fn saturating_cast(self) -> f32 {
+                self.0
+            }
+
+            fn checked_cast(self) -> Option<f32> {
+                Some(self.0)
+            }
+        }
+    };
+    (@binop $t: ident, $op: ident, $f: ident) => {
+        impl $op for $t {
+            type Output = $t;
+
+            fn $f(self, rhs: $t) -> $t {
+                $t::new(self.0.$f(rhs.0))
+            }
+        }
+    };
+}
+
+// The constants are the values `new` rounds them to: `Lowp` saturates π and infinity to its
+// largest value and maps NaN to zero.
+impl_precision!(
+    Mediump,
+    round_mediump,
+    1.0 / 1024.0,
+    3.140625,
+    f32::INFINITY,
+    f32::NAN
+);
+impl_precision!(
+    Lowp,
+    round_lowp,
+    1.0 / 256.0,
+    511.0 / 256.0,
+    511.0 / 256.0,
+    0.0
+);
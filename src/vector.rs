@@ -1,40 +1,189 @@
+mod componentwise;
+mod constant;
+mod iter;
+mod ops;
 pub mod v2;
 pub mod v3;
-pub mod v4;
 
 use crate::float::Float;
-use crate::numeric::Numeric;
-use std::ops::{Div, Sub};
+use crate::numeric::{Numeric, One, Zero};
+use crate::scalar::Conjugate;
+use std::ops::{Deref, DerefMut};
 
-#[derive(Default, Copy, Clone, PartialEq, Debug)]
-pub struct V2<T>(pub [T; 2]);
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Vector<T, const N: usize>(pub [T; N]);
 
-#[derive(Default, Copy, Clone, PartialEq, Debug)]
-pub struct V3<T>(pub [T; 3]);
+pub type V2<T> = Vector<T, 2>;
+pub type V3<T> = Vector<T, 3>;
+pub type V4<T> = Vector<T, 4>;
 
-#[derive(Default, Copy, Clone, PartialEq, Debug)]
-pub struct V4<T>(pub [T; 4]);
+#[allow(non_snake_case)]
+pub const fn V2<T>(v: [T; 2]) -> V2<T> {
+    Vector(v)
+}
+
+#[allow(non_snake_case)]
+pub const fn V3<T>(v: [T; 3]) -> V3<T> {
+    Vector(v)
+}
+
+#[allow(non_snake_case)]
+pub const fn V4<T>(v: [T; 4]) -> V4<T> {
+    Vector(v)
+}
 
-pub trait Vector<T>
+impl<T, const N: usize> Default for Vector<T, N>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Vector(std::array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T, const N: usize> Zero for Vector<T, N>
 where
     T: Numeric,
 {
-    fn dot(&self, rhs: Self) -> T;
+    const ZERO: Self = Vector([T::ZERO; N]);
 }
 
-pub trait FloatVector<F>: Vector<F>
+impl<T, const N: usize> One for Vector<T, N>
+where
+    T: Numeric,
+{
+    const ONE: Self = Vector([T::ONE; N]);
+}
+
+impl<T, const N: usize> Vector<T, N> {
+    pub const fn new(components: [T; N]) -> Self {
+        Vector(components)
+    }
+
+    pub const fn splat(v: T) -> Self
+    where
+        T: Copy,
+    {
+        Vector([v; N])
+    }
+
+    /// The `i`-th component; unlike indexing through `Deref`, this is usable in `const`.
+    pub const fn component(&self, i: usize) -> T
+    where
+        T: Copy,
+    {
+        self.0[i]
+    }
+}
+
+macro_rules! impl_accessors {
+    ($n: expr, $($name: ident: $i: expr),+) => {
+        impl<T> Vector<T, $n>
+        where
+            T: Copy,
+        {
+            $(
+                pub const fn $name(&self) -> T {
+                    self.0[$i]
+                }
+            )+
+        }
+    };
+}
+
+impl_accessors!(2, x: 0, y: 1);
+impl_accessors!(3, x: 0, y: 1, z: 2);
+impl_accessors!(4, x: 0, y: 1, z: 2, w: 3);
+
+/// Unit vectors along the axes.
+impl<T> Vector<T, 2>
+where
+    T: Numeric,
+{
+    pub const X: Self = Vector([T::ONE, T::ZERO]);
+    pub const Y: Self = Vector([T::ZERO, T::ONE]);
+}
+
+impl<T> Vector<T, 3>
+where
+    T: Numeric,
+{
+    pub const X: Self = Vector([T::ONE, T::ZERO, T::ZERO]);
+    pub const Y: Self = Vector([T::ZERO, T::ONE, T::ZERO]);
+    pub const Z: Self = Vector([T::ZERO, T::ZERO, T::ONE]);
+}
+
+impl<T> Vector<T, 4>
+where
+    T: Numeric,
+{
+    pub const X: Self = Vector([T::ONE, T::ZERO, T::ZERO, T::ZERO]);
+    pub const Y: Self = Vector([T::ZERO, T::ONE, T::ZERO, T::ZERO]);
+    pub const Z: Self = Vector([T::ZERO, T::ZERO, T::ONE, T::ZERO]);
+    pub const W: Self = Vector([T::ZERO, T::ZERO, T::ZERO, T::ONE]);
+}
+
+impl<T, const N: usize> Deref for Vector<T, N> {
+    type Target = [T; N];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> DerefMut for Vector<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T, const N: usize> Vector<T, N>
+where
+    T: Numeric,
+{
+    pub fn dot(&self, rhs: Self) -> T {
+        self.iter()
+            .zip(rhs.iter())
+            .fold(T::default(), |sum, (&a, &b)| sum + a * b)
+    }
+}
+
+impl<T, const N: usize> Vector<T, N>
+where
+    T: Conjugate,
+{
+    /// The complex inner product `Σ conj(self[i]) * rhs[i]`, which equals `dot` for real
+    /// components.
+    pub fn hermitian_dot(&self, rhs: Self) -> T {
+        self.iter()
+            .zip(rhs.iter())
+            .fold(T::default(), |sum, (&a, &b)| sum + a.conj() * b)
+    }
+}
+
+pub trait FloatVector<F>
+where
+    F: Float,
+{
+    fn length(&self) -> F;
+    fn normalize(&self) -> Self;
+    fn distance(&self, rhs: &Self) -> F;
+}
+
+impl<F, const N: usize> FloatVector<F> for Vector<F, N>
 where
     F: Float,
-    Self: Clone + Div<F, Output = Self> + Sub<Output = Self>,
 {
     fn length(&self) -> F {
-        self.dot(self.clone()).f_sqrt()
+        self.dot(*self).f_sqrt()
     }
+
     fn normalize(&self) -> Self {
-        self.clone() / self.length()
+        *self / self.length()
     }
+
     fn distance(&self, rhs: &Self) -> F {
-        (self.clone() - rhs.clone()).length()
+        (*self - *rhs).length()
     }
 }
 
@@ -0,0 +1,71 @@
+use crate::numeric::Numeric;
+use crate::vector::Vector;
+
+impl<T, const N: usize> Vector<T, N> {
+    /// Applies `f` to every component, possibly changing the component type.
+    pub fn map<U, F>(self, f: F) -> Vector<U, N>
+    where
+        F: FnMut(T) -> U,
+    {
+        Vector(self.0.map(f))
+    }
+
+    /// Combines the components of `self` and `other` pairwise.
+    pub fn zip_with<U, V, F>(self, other: Vector<U, N>, mut f: F) -> Vector<V, N>
+    where
+        T: Copy,
+        U: Copy,
+        F: FnMut(T, U) -> V,
+    {
+        Vector(std::array::from_fn(|i| f(self[i], other[i])))
+    }
+
+    pub fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        self.into_iter().fold(init, f)
+    }
+
+    /// Folds the components using the first one as the initial value.
+    ///
+    /// Panics if `N == 0`.
+    pub fn reduce<F>(self, f: F) -> T
+    where
+        F: FnMut(T, T) -> T,
+    {
+        self.into_iter()
+            .reduce(f)
+            .expect("reduce on a vector without components")
+    }
+
+    /// Returns the smallest component. Panics if `N == 0`.
+    pub fn min_element(self) -> T
+    where
+        T: PartialOrd,
+    {
+        self.reduce(|min, e| if e < min { e } else { min })
+    }
+
+    /// Returns the largest component. Panics if `N == 0`.
+    pub fn max_element(self) -> T
+    where
+        T: PartialOrd,
+    {
+        self.reduce(|max, e| if e > max { e } else { max })
+    }
+
+    pub fn sum_elements(self) -> T
+    where
+        T: Numeric,
+    {
+        self.fold(T::default(), |sum, e| sum + e)
+    }
+
+    pub fn product_elements(self) -> T
+    where
+        T: Numeric,
+    {
+        self.fold(T::ONE, |product, e| product * e)
+    }
+}
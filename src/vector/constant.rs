@@ -0,0 +1,76 @@
+//! `const fn` arithmetic for vectors of primitive numbers. Operator traits cannot be called in
+//! `const` contexts, so these mirror the operators under a `const_` prefix. They are inherent
+//! methods per element type, so the element type must already be known where they are called.
+
+use crate::vector::Vector;
+
+macro_rules! impl_const_ops {
+    ($($t: ty),*) => {
+        $(
+            impl<const N: usize> Vector<$t, N> {
+                pub const fn const_add(self, rhs: Self) -> Self {
+                    let mut out = self.0;
+                    let mut i = 0;
+                    while i < N {
+                        out[i] += rhs.0[i];
+                        i += 1;
+                    }
+                    Vector(out)
+                }
+
+                pub const fn const_sub(self, rhs: Self) -> Self {
+                    let mut out = self.0;
+                    let mut i = 0;
+                    while i < N {
+                        out[i] -= rhs.0[i];
+                        i += 1;
+                    }
+                    Vector(out)
+                }
+
+                /// Component-wise product, like `*`.
+                pub const fn const_mul(self, rhs: Self) -> Self {
+                    let mut out = self.0;
+                    let mut i = 0;
+                    while i < N {
+                        out[i] *= rhs.0[i];
+                        i += 1;
+                    }
+                    Vector(out)
+                }
+
+                pub const fn const_scale(self, s: $t) -> Self {
+                    let mut out = self.0;
+                    let mut i = 0;
+                    while i < N {
+                        out[i] *= s;
+                        i += 1;
+                    }
+                    Vector(out)
+                }
+
+                pub const fn const_neg(self) -> Self {
+                    let mut out = self.0;
+                    let mut i = 0;
+                    while i < N {
+                        out[i] = -out[i];
+                        i += 1;
+                    }
+                    Vector(out)
+                }
+
+                pub const fn const_dot(self, rhs: Self) -> $t {
+                    let mut sum = 0 as $t;
+                    let mut i = 0;
+                    while i < N {
+                        sum += self.0[i] * rhs.0[i];
+                        i += 1;
+                    }
+                    sum
+                }
+            }
+        )*
+    };
+}
+
+impl_const_ops!(i8, i16, i32, i64, f32, f64);
@@ -0,0 +1,103 @@
+use crate::numeric::{Numeric, One};
+use crate::vector::Vector;
+use std::iter::{Product, Sum};
+
+impl<T, const N: usize> Vector<T, N> {
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.0.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.0.iter_mut()
+    }
+
+    /// Collects exactly `N` components, returning `None` if the iterator yields more or fewer.
+    /// The iterator is not polled again once it has returned `None`.
+    pub fn try_from_iter<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter().fuse();
+        let components = [(); N].map(|_| iter.next());
+        if iter.next().is_some() || components.iter().any(Option::is_none) {
+            return None;
+        }
+        Some(Vector(components.map(Option::unwrap)))
+    }
+}
+
+impl<T, const N: usize> IntoIterator for Vector<T, N> {
+    type Item = T;
+    type IntoIter = std::array::IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter(self.0)
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a Vector<T, N> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut Vector<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+impl<T, const N: usize> Sum for Vector<T, N>
+where
+    T: Numeric,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Vector::default(), |sum, v| sum + v)
+    }
+}
+
+impl<'a, T, const N: usize> Sum<&'a Vector<T, N>> for Vector<T, N>
+where
+    T: Numeric,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.copied().sum()
+    }
+}
+
+/// Component-wise product; the product of no vectors is `Vector::ONE`.
+impl<T, const N: usize> Product for Vector<T, N>
+where
+    T: Numeric,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Vector::ONE, |product, v| product * v)
+    }
+}
+
+impl<'a, T, const N: usize> Product<&'a Vector<T, N>> for Vector<T, N>
+where
+    T: Numeric,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.copied().product()
+    }
+}
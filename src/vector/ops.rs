@@ -0,0 +1,92 @@
+use crate::matrix::Matrix;
+use crate::numeric::Numeric;
+use crate::vector::Vector;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+macro_rules! impl_componentwise_op {
+    ($op: ident, $f: ident, $($bound: tt)+) => {
+        impl<T, const N: usize> $op for Vector<T, N>
+        where
+            T: $($bound)+,
+        {
+            type Output = Vector<T, N>;
+
+            fn $f(self, rhs: Self) -> Self::Output {
+                Vector(std::array::from_fn(|i| self[i].$f(rhs[i])))
+            }
+        }
+
+        impl<T, const N: usize> $op<T> for Vector<T, N>
+        where
+            T: $($bound)+,
+        {
+            type Output = Vector<T, N>;
+
+            fn $f(self, rhs: T) -> Self::Output {
+                Vector(std::array::from_fn(|i| self[i].$f(rhs)))
+            }
+        }
+
+        forward_ref_binop!(
+            [T, const N: usize] $op, $f, Vector<T, N>, Vector<T, N>, [T: $($bound)+]
+        );
+        forward_ref_binop!([T, const N: usize] $op, $f, Vector<T, N>, T, [T: $($bound)+]);
+    };
+}
+
+impl_componentwise_op!(Add, add, Numeric);
+impl_componentwise_op!(Sub, sub, Numeric);
+impl_componentwise_op!(Mul, mul, Numeric);
+impl_componentwise_op!(Div, div, Numeric);
+impl_componentwise_op!(Rem, rem, Numeric + Rem<Output = T>);
+
+macro_rules! impl_assign_op {
+    ($op: ident, $f: ident, $binop: ident, $binf: ident) => {
+        impl<T, Rhs, const N: usize> $op<Rhs> for Vector<T, N>
+        where
+            Vector<T, N>: Copy + $binop<Rhs, Output = Vector<T, N>>,
+        {
+            fn $f(&mut self, rhs: Rhs) {
+                *self = (*self).$binf(rhs);
+            }
+        }
+    };
+}
+
+impl_assign_op!(AddAssign, add_assign, Add, add);
+impl_assign_op!(SubAssign, sub_assign, Sub, sub);
+impl_assign_op!(MulAssign, mul_assign, Mul, mul);
+impl_assign_op!(DivAssign, div_assign, Div, div);
+impl_assign_op!(RemAssign, rem_assign, Rem, rem);
+
+impl<T, const N: usize> Neg for Vector<T, N>
+where
+    T: Numeric,
+{
+    type Output = Vector<T, N>;
+
+    fn neg(self) -> Self::Output {
+        Vector(std::array::from_fn(|i| -self[i]))
+    }
+}
+
+forward_ref_unop!([T, const N: usize] Neg, neg, Vector<T, N>, [T: Numeric]);
+
+impl<T, const R: usize, const C: usize> Mul<Matrix<T, R, C>> for Vector<T, R>
+where
+    T: Numeric,
+{
+    type Output = Vector<T, C>;
+
+    fn mul(self, rhs: Matrix<T, R, C>) -> Self::Output {
+        Vector(std::array::from_fn(|j| {
+            (0..R).fold(T::default(), |sum, i| sum + self[i] * rhs[i][j])
+        }))
+    }
+}
+
+forward_ref_binop!(
+    [T, const R: usize, const C: usize] Mul, mul, Vector<T, R>, Matrix<T, R, C>, [T: Numeric]
+);
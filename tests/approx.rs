@@ -0,0 +1,60 @@
+#[macro_use]
+extern crate glsl_linalg;
+
+use glsl_linalg::approx::ApproxEq;
+
+#[test]
+fn f_abs_diff_eq() {
+    assert!(1.0f64.abs_diff_eq(&1.05, 0.1));
+    assert!(!1.0f64.abs_diff_eq(&1.2, 0.1));
+    assert!(!f32::NAN.abs_diff_eq(&f32::NAN, 1.0));
+    assert_abs_diff_eq!(0.1 + 0.2, 0.3);
+}
+
+#[test]
+fn f_relative_eq() {
+    assert!(1e10f64.relative_eq(&(1e10 + 1.0), 0.0, 1e-9));
+    assert!(!1e10f64.relative_eq(&(1e10 + 100.0), 0.0, 1e-9));
+    assert!(f64::INFINITY.relative_eq(&f64::INFINITY, 0.0, 0.0));
+    assert!(!f64::INFINITY.relative_eq(&f64::MAX, 1.0, 1.0));
+    assert_relative_eq!(1e10f32, 1.0001e10, max_relative = 1e-3);
+}
+
+#[test]
+fn f_ulps_eq() {
+    let a = 1.0f32;
+    let b = f32::from_bits(a.to_bits() + 3);
+
+    assert!(a.ulps_eq(&b, 0.0, 3));
+    assert!(!a.ulps_eq(&b, 0.0, 2));
+    assert!(!(-0.1f64).ulps_eq(&0.1, 0.0, u32::MAX));
+    assert!(!f64::NAN.ulps_eq(&f64::NAN, 0.0, 4));
+    assert!(!f32::NAN.ulps_eq(&1.0, f32::INFINITY, u32::MAX));
+    assert_ulps_eq!(0.1 + 0.2, 0.3, max_ulps = 1);
+}
+
+#[test]
+fn v_approx_eq() {
+    let v = vec3!(1.0, 2.0, 3.0);
+
+    assert!(v.abs_diff_eq(&vec3!(1.0, 2.05, 3.0), 0.1));
+    assert!(!v.abs_diff_eq(&vec3!(1.0, 2.0, 3.2), 0.1));
+    assert_relative_eq!(vec4!(0.3), vec4!(0.1 + 0.2));
+    assert_ulps_eq!(vec2!(1.0, 0.3), vec2!(1.0, 0.1 + 0.2));
+}
+
+#[test]
+fn m_approx_eq() {
+    let m = mat2!(1.0, 2.0, 3.0, 4.0);
+
+    assert!(m.abs_diff_eq(&mat2!(1.0, 2.0, 3.0, 4.05), 0.1));
+    assert!(!m.abs_diff_eq(&mat2!(1.0, 2.0, 3.2, 4.0), 0.1));
+    assert_relative_eq!(mat3!(0.3), mat3!(0.1 + 0.2), epsilon = 0.0);
+    assert_abs_diff_eq!(mat4!(1.0), mat4!(1.01), epsilon = 0.1);
+}
+
+#[test]
+#[should_panic]
+fn assert_approx_eq_fails() {
+    assert_relative_eq!(vec2!(1.0, 2.0), vec2!(1.0, 2.1));
+}
@@ -0,0 +1,76 @@
+#[macro_use]
+extern crate glsl_linalg;
+
+use glsl_linalg::cast::Cast;
+use glsl_linalg::{M2, M3, V3, V4};
+
+#[test]
+fn v_from() {
+    let v: V3<f32> = vec3!(1i16, -2, 3).into();
+    assert_eq!(vec3!(1.0, -2.0, 3.0), v);
+
+    let v: V4<f64> = V4::from(vec4!(1.5f32));
+    assert_eq!(vec4!(1.5), v);
+}
+
+#[test]
+fn m_from() {
+    let m: M2<i64> = mat2!(1i16, 2, 3, 4).into();
+    assert_eq!(mat2!(1, 2, 3, 4), m);
+}
+
+#[test]
+fn v_cast() {
+    assert_eq!(vec3!(1, -2, 3), vec3!(1.7, -2.9, 3.0).cast::<i32>());
+    assert_eq!(vec3!(1.0, 2.0, 3.0), vec3!(1, 2, 3).cast::<f32>());
+    assert_eq!(vec2!(44i8, -1), vec2!(300, 255).cast::<i8>());
+    assert_eq!(vec2!(0i8, 127), vec2!(f32::NAN, 1e10).cast::<i8>());
+}
+
+#[test]
+fn v_saturating_cast() {
+    assert_eq!(vec2!(127i8, -128), vec2!(300, -255).saturating_cast::<i8>());
+    assert_eq!(
+        vec2!(i32::MAX, i32::MIN),
+        vec2!(1e20, -1e20).saturating_cast::<i32>()
+    );
+    assert_eq!(
+        vec2!(f32::MAX, 1.0),
+        vec2!(1e300, 1.0).saturating_cast::<f32>()
+    );
+}
+
+#[test]
+fn v_checked_cast() {
+    assert_eq!(
+        Some(vec3!(1i8, -2, 127)),
+        vec3!(1.5, -2.5, 127.9).checked_cast()
+    );
+    assert_eq!(None, vec3!(1.0, 128.0, 0.0).checked_cast::<i8>());
+    assert_eq!(None, vec2!(f64::NAN, 0.0).checked_cast::<i64>());
+    assert_eq!(
+        None,
+        vec2!(9.223_372_036_854_776e18, 0.0).checked_cast::<i64>()
+    );
+    assert_eq!(
+        Some(vec2!(i64::MIN, 0)),
+        vec2!(-9.223_372_036_854_776e18, 0.0).checked_cast()
+    );
+    assert_eq!(None, vec2!(300, 0).checked_cast::<i8>());
+    assert_eq!(None, vec2!(1e300, 0.0).checked_cast::<f32>());
+    assert_eq!(
+        Some(vec2!(f32::INFINITY, 0.0)),
+        vec2!(f64::INFINITY, 0.0).checked_cast()
+    );
+}
+
+#[test]
+fn m_cast() {
+    let m: M3<f64> = mat3!(1.5, -1.5, 2.0, 0.0, 1e10, 3.0, 4.0, 5.0, 6.0);
+
+    assert_eq!(mat3!(1, -1, 2, 0, 2147483647, 3, 4, 5, 6), m.cast::<i32>());
+    assert_eq!(m.cast::<i32>(), m.saturating_cast::<i32>());
+    assert_eq!(None, m.checked_cast::<i32>());
+    assert_eq!(Some(mat2!(1i16, 2, 3, 4)), mat2!(1, 2, 3, 4).checked_cast());
+    assert_eq!(1.5f32, Cast::<f32>::cast(1.5f64));
+}
@@ -0,0 +1,113 @@
+#[macro_use]
+extern crate glsl_linalg;
+
+use glsl_linalg::float::Float;
+use glsl_linalg::scalar::{f16, Complex, Conjugate, Dual, Interval, Lowp, Mediump, DD, Q16_16};
+use glsl_linalg::{M2, V2};
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+use std::fmt::Debug;
+
+fn c(re: f64, im: f64) -> Complex<f64> {
+    Complex::new(re, im)
+}
+
+#[test]
+fn arithmetic() {
+    let z = c(3.0, 4.0);
+    assert_eq!(c(3.0, -4.0), z.conj());
+    assert_eq!(25.0, z.norm_sqr());
+    assert_eq!(5.0, z.abs());
+    assert_eq!(f64::INFINITY, c(f64::INFINITY, 1.0).abs());
+    assert_eq!(f64::INFINITY, c(f64::NAN, f64::NEG_INFINITY).abs());
+    assert_eq!(f64::INFINITY, c(f64::INFINITY, f64::NAN).abs());
+    assert_eq!(f64::INFINITY, c(f64::INFINITY, 1.0).sqrt().re);
+    assert_eq!(0.0, c(f64::INFINITY, 1.0).powf(-1.0).abs());
+    assert_eq!(c(-7.0, 24.0), z * z);
+    assert_eq!(c(1.0, 0.0), z / z);
+    assert_eq!(c(0.12, -0.16), z.recip());
+    assert_eq!(c(-1.0, 0.0), Complex::i() * Complex::i());
+    assert_eq!(1e300, c(6e299, 8e299).abs());
+
+    // Integer components work for everything but the modulus.
+    assert_eq!(
+        Complex::new(-5, 10),
+        Complex::new(1, 2) * Complex::new(3, 4)
+    );
+}
+
+#[test]
+fn transcendental() {
+    assert_relative_eq!(c(-1.0, 0.0), c(0.0, PI).exp(), epsilon = 1e-15);
+    assert_relative_eq!(c(0.0, PI / 2.0), Complex::<f64>::i().ln());
+    assert_relative_eq!(c(FRAC_1_SQRT_2, FRAC_1_SQRT_2), Complex::<f64>::i().sqrt());
+    assert_relative_eq!(c(0.0, 2.0), c(-4.0, 0.0).sqrt(), epsilon = 1e-15);
+    assert_relative_eq!(PI / 2.0, Complex::<f64>::i().arg());
+    assert_relative_eq!(c(-7.0, 24.0), c(3.0, 4.0).powf(2.0), epsilon = 1e-13);
+    assert_relative_eq!(
+        c(1.0, 1.0),
+        Complex::<f64>::from_polar(2f64.sqrt(), PI / 4.0)
+    );
+}
+
+#[test]
+fn vectors() {
+    let u = V2([c(1.0, 1.0), c(0.0, 2.0)]);
+    let v = V2([c(2.0, 0.0), c(1.0, -1.0)]);
+    assert_eq!(c(0.0, 0.0), u.hermitian_dot(v) - v.hermitian_dot(u).conj());
+    assert_eq!(c(6.0, 0.0), u.hermitian_dot(u));
+    assert_eq!(c(0.0, -4.0), u.hermitian_dot(v));
+    assert_eq!(V2([c(-1.0, 1.0), c(-2.0, 0.0)]), Complex::i() * u);
+
+    // Real components reduce to the ordinary dot product.
+    assert_eq!(11, vec2!(1, 2).hermitian_dot(vec2!(3, 4)));
+}
+
+fn real_conjugate<F>()
+where
+    F: Float + Conjugate + Debug,
+{
+    let f = F::f_from_i32;
+    assert_eq!(f(-3), f(-3).conj());
+    let (u, v) = (V2([f(1), f(2)]), V2([f(3), f(-4)]));
+    assert_eq!(u.dot(v), u.hermitian_dot(v));
+    let m = M2([[f(1), f(2)], [f(3), f(4)]]);
+    assert_eq!(m.transposed(), m.conjugate_transposed());
+}
+
+#[test]
+fn real_scalar_types() {
+    real_conjugate::<f16>();
+    real_conjugate::<DD>();
+    real_conjugate::<Q16_16>();
+    real_conjugate::<Dual<f64>>();
+    real_conjugate::<Interval<f64>>();
+    real_conjugate::<Mediump>();
+    real_conjugate::<Lowp>();
+}
+
+#[test]
+fn unitary_gates() {
+    let h = M2([[1.0, 1.0], [1.0, -1.0]]).map(|e| c(e * FRAC_1_SQRT_2, 0.0));
+    let s = M2([[c(1.0, 0.0), c(0.0, 0.0)], [c(0.0, 0.0), Complex::i()]]);
+    let identity = M2([[c(1.0, 0.0), c(0.0, 0.0)], [c(0.0, 0.0), c(1.0, 0.0)]]);
+
+    for gate in [h, s, h * s * h] {
+        assert_relative_eq!(
+            identity,
+            gate.conjugate_transposed() * gate,
+            epsilon = 1e-15
+        );
+    }
+    assert_eq!(
+        M2([[c(1.0, 0.0), c(0.0, 0.0)], [c(0.0, 0.0), c(0.0, -1.0)]]),
+        s.conjugate_transposed()
+    );
+
+    let zero = V2([c(1.0, 0.0), c(0.0, 0.0)]);
+    let plus = h * zero;
+    assert_relative_eq!(c(1.0, 0.0), plus.hermitian_dot(plus), epsilon = 1e-15);
+    assert_relative_eq!(V2([c(FRAC_1_SQRT_2, 0.0), c(0.0, FRAC_1_SQRT_2)]), s * plus);
+
+    let m = mat2!(1, 2, 3, 4);
+    assert_eq!(m.transposed(), m.conjugate_transposed());
+}
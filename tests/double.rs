@@ -0,0 +1,116 @@
+#[macro_use]
+extern crate glsl_linalg;
+
+use glsl_linalg::approx::ApproxEq;
+use glsl_linalg::float::Float;
+use glsl_linalg::matrix::MatrixFunction;
+use glsl_linalg::scalar::DD;
+use glsl_linalg::{M4, V4};
+
+fn dd(v: f64) -> DD {
+    DD::from(v)
+}
+
+#[test]
+fn extended_precision() {
+    let third = dd(1.0) / dd(3.0);
+    assert_eq!(1.0 / 3.0, third.hi());
+    assert!(third.lo() != 0.0);
+    assert!((third * dd(3.0) - dd(1.0)).f_abs() < dd(1e-31));
+
+    // 1 + 2^-60 is lost in f64 but kept here.
+    let tiny = dd(2f64.powi(-60));
+    let sum = dd(1.0) + tiny;
+    assert_eq!(1.0, sum.hi());
+    assert_eq!(tiny, sum - dd(1.0));
+
+    let root = dd(2.0).f_sqrt();
+    assert!((root * root - dd(2.0)).f_abs() < dd(1e-31));
+    assert_eq!(dd(-3.0), dd(-3.0).f_abs() * dd(-1.0));
+
+    assert_eq!(dd(1.0), dd(7.0) % dd(3.0));
+    assert_eq!(dd(-1.0), dd(-7.0) % dd(3.0));
+    assert_eq!(dd(-1.0), (dd(-1.0) - tiny).trunc());
+    assert_eq!(dd(-2.0), (dd(-1.0) - tiny).floor());
+    assert_eq!(dd(1.0), (dd(1.0) - tiny).ceil());
+}
+
+#[test]
+fn rounding_to_f32() {
+    // Halfway between 1 and the next f32 in `hi`; `lo` decides the direction.
+    let half_ulp = f64::from(f32::EPSILON) / 2.0;
+    assert_eq!(1.0f32, DD::new(1.0 + half_ulp, 0.0).to_f32());
+    assert_eq!(1.0f32.next_up(), DD::new(1.0 + half_ulp, 1e-30).to_f32());
+    assert_eq!(1.0f32, DD::new(1.0 + half_ulp, -1e-30).to_f32());
+    assert_eq!(0.1f32, dd(0.1).to_f32());
+}
+
+#[test]
+fn approx_eq_infinities() {
+    let inf = DD::INFINITY;
+    assert!(inf.abs_diff_eq(&inf, DD::EPSILON));
+    assert!(inf.relative_eq(&inf, DD::EPSILON, DD::EPSILON));
+    assert!(inf.ulps_eq(&inf, DD::EPSILON, 4));
+    assert!(!inf.relative_eq(&dd(1.0), DD::EPSILON, DD::EPSILON));
+    assert!(!(-inf).ulps_eq(&inf, DD::EPSILON, 4));
+    assert!(!DD::NAN.abs_diff_eq(&DD::NAN, DD::EPSILON));
+}
+
+#[test]
+fn transcendental() {
+    let close = |a: DD, b: DD| (a - b).f_abs() < dd(1e-30) * b.f_abs().f_max(dd(1.0));
+    let e = DD::new(std::f64::consts::E, 1.4456468917292502e-16);
+    let ln_2 = DD::new(std::f64::consts::LN_2, 2.3190468138462996e-17);
+
+    assert!(close(e, dd(1.0).f_exp()));
+    assert!(close(ln_2, dd(2.0).f_ln()));
+    assert!(close(dd(1.0), e.f_ln()));
+    assert!(close(dd(10.0), dd(10.0).f_ln().f_exp()));
+    assert!(close(dd(1.0), dd(600.0).f_exp() * dd(-600.0).f_exp()));
+    assert!(close(ln_2 * dd(-1000.0), dd(2f64.powi(-1000)).f_ln()));
+    assert!(close(DD::PI, dd(1.0).f_atan() * dd(4.0)));
+    assert!(close(DD::PI * dd(0.75), dd(1.0).f_atan2(dd(-1.0))));
+    assert!(close(dd(0.5), (DD::PI / dd(6.0)).f_sin()));
+    assert!(close(dd(0.5), (DD::PI / dd(3.0)).f_cos()));
+    assert!(close(dd(-1.0), (DD::PI * dd(0.75)).f_tan()));
+    for x in [0.1, 2.0, -3.5, 100.0, 1e5] {
+        let (s, c) = (dd(x).f_sin(), dd(x).f_cos());
+        assert!(close(dd(1.0), s * s + c * c));
+        assert!((x.sin() - s.to_f64()).abs() <= f64::EPSILON);
+    }
+
+    assert_eq!(DD::INFINITY, dd(710.0).f_exp());
+    assert_eq!(DD::ZERO, dd(-746.0).f_exp());
+    assert!(dd(-1.0).f_ln().f_is_nan());
+    assert_eq!(-DD::INFINITY, dd(0.0).f_ln());
+}
+
+#[test]
+fn matrix_chains() {
+    let angle = 1e-3f64;
+    let (s, c) = angle.sin_cos();
+    let rotation = mat4!(c, -s, 0.0, 0.0, s, c, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+    let wide = rotation.map(DD::from);
+
+    let mut m = M4::<f64>::identity();
+    let mut w = M4::<DD>::identity();
+    for _ in 0..1000 {
+        m *= rotation;
+        w *= wide;
+    }
+    // Sequential products and repeated squaring round differently; double-double keeps the
+    // two orders in agreement far beyond f64.
+    let p = V4([6.4e6, 0.0, 0.0, 1.0]);
+    let f64_gap = (m * p - rotation.powi(1000) * p)
+        .map(f64::abs)
+        .max_element();
+    let dd_gap = (w * p.map(DD::from) - wide.powi(1000) * p.map(DD::from))
+        .map(DD::f_abs)
+        .max_element();
+    assert!(f64_gap > 1e-12);
+    assert!(dd_gap < dd(1e-20));
+
+    let rounded = w.cast::<f32>();
+    assert_eq!(1.0, rounded[2][2]);
+    assert_eq!(m.cast::<f32>()[0][0], rounded[0][0]);
+}
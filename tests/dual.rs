@@ -0,0 +1,133 @@
+#[macro_use]
+extern crate glsl_linalg;
+
+use glsl_linalg::float::Float;
+use glsl_linalg::matrix::FloatMatrix;
+use glsl_linalg::scalar::{gradient, jacobian, Dual};
+use glsl_linalg::vector::{Cross, FloatVector, Vector};
+use glsl_linalg::{M3, V2, V3};
+
+#[test]
+fn derivatives() {
+    let x = Dual::variable(3.0);
+    assert_eq!(Dual::new(9.0, 6.0), x * x);
+    assert_eq!(Dual::new(1.0 / 3.0, -1.0 / 9.0), Dual::constant(1.0) / x);
+    assert_eq!(Dual::new(2.0, 0.25), Dual::variable(4.0).f_sqrt());
+    assert_eq!(Dual::new(3.0, 1.0), (-x).f_abs());
+    assert_eq!(
+        Dual::new(1.0, 1.0),
+        Dual::variable(7.0) % Dual::constant(3.0)
+    );
+
+    let x = Dual::variable(0.5f64);
+    assert_relative_eq!(Dual::new(0.5f64.exp(), 0.5f64.exp()), x.exp());
+    assert_relative_eq!(Dual::new(0.5f64.ln(), 2.0), x.ln());
+    assert_relative_eq!(Dual::new(0.5f64.sin(), 0.5f64.cos()), x.sin());
+    assert_relative_eq!(Dual::new(0.125, 0.75), x.powi(3));
+    assert_relative_eq!(Dual::new(0.125, 0.75), x.powf(3.0));
+}
+
+#[test]
+fn singular_derivatives() {
+    // Constants keep a zero derivative where the derivative of the function is infinite.
+    let zero = Dual::constant(0.0f64);
+    assert_eq!(Dual::constant(0.0), zero.f_sqrt());
+    assert_eq!(Dual::constant(1.0), zero.powi(0));
+    assert_eq!(Dual::constant(f64::NEG_INFINITY), zero.f_ln());
+
+    let x = Dual::variable(0.0f64);
+    assert_eq!(Dual::constant(1.0), x.powi(0));
+    assert_eq!(Dual::new(0.0, f64::INFINITY), x.f_sqrt());
+
+    // The same holds for operations on two constants.
+    let one = Dual::constant(1.0f64);
+    let inf = Dual::constant(f64::INFINITY);
+    assert_eq!(inf, one / zero);
+    assert_eq!(Dual::constant(0.0), zero.f_atan2(zero));
+    assert_eq!(inf, inf * Dual::constant(2.0));
+    // The truncated quotient overflows to infinity.
+    assert_eq!(Dual::constant(1.0 % 1e-320), one % Dual::constant(1e-320));
+    assert_eq!(Dual::constant(0.0), zero.f_powf(Dual::constant(0.5)));
+    assert!(!(one / zero).f_is_nan() && !zero.f_atan2(zero).f_is_nan());
+}
+
+#[test]
+fn float_interface() {
+    // Constants, including the tolerance, do not depend on the variable.
+    assert_eq!(Dual::constant(f64::EPSILON), Dual::<f64>::EPSILON);
+    assert_eq!(Dual::constant(std::f64::consts::PI), Dual::<f64>::PI);
+
+    let x = Dual::variable(2.5f64);
+    assert_eq!(Dual::constant(2.0), x.f_floor());
+    assert_eq!(Dual::new(-2.5, -1.0), x.f_copysign(Dual::constant(-1.0)));
+    assert_eq!(Dual::constant(1.0), x.f_min(Dual::constant(1.0)));
+    assert_eq!(x, x.f_max(Dual::constant(1.0)));
+    assert_eq!(
+        Dual::new(12.5, 5.0),
+        x.f_mul_add(Dual::constant(3.0), x.f_floor() * x)
+    );
+    assert_relative_eq!(
+        Dual::new(2.5f64.tan(), 1.0 / (2.5f64.cos() * 2.5f64.cos())),
+        x.f_tan()
+    );
+
+    // d/dp 2^p = 2^p ln 2.
+    let p = Dual::constant(2.0).f_powf(Dual::variable(3.0));
+    assert_relative_eq!(Dual::new(8.0, 8.0 * 2f64.ln()), p);
+
+    // ∇ atan2(y, x) = (-y, x) / (x² + y²).
+    assert_relative_eq!(
+        V2([-4.0 / 25.0, 3.0 / 25.0]),
+        gradient(|v: V2<Dual<f64>>| v[1].f_atan2(v[0]), V2([3.0, 4.0]))
+    );
+}
+
+#[test]
+fn gradients() {
+    let x = V3([1.0, -2.0, 3.0]);
+    assert_eq!(
+        V3([2.0, -4.0, 6.0]),
+        gradient(|v: V3<Dual<f64>>| v.dot(v), x)
+    );
+    assert_relative_eq!(x.normalize(), gradient(|v: V3<_>| v.length(), x));
+    assert_eq!(
+        V3([1.0, 2.0, 3.0]),
+        gradient(|v: V3<_>| v.dot(V3([1.0, 2.0, 3.0]).map(Dual::from)), x)
+    );
+}
+
+#[test]
+fn jacobians() {
+    let m = M3([[2.0, 0.0, 1.0], [1.0, 3.0, 0.0], [0.0, 1.0, 4.0]]);
+    let x = V3([1.0, 2.0, 3.0]);
+    assert_eq!(m, jacobian(|v: V3<Dual<f64>>| m.map(Dual::from) * v, x));
+
+    // d(a × x)/dx is the skew-symmetric cross product matrix of a.
+    let a = V3([1.0, 2.0, 3.0]);
+    assert_eq!(
+        M3([[0.0, -3.0, 2.0], [3.0, 0.0, -1.0], [-2.0, 1.0, 0.0]]),
+        jacobian(|v| a.map(Dual::from).cross(v), x)
+    );
+
+    // Non-square: f(x, y, z) = (x y, z).
+    let j = jacobian(|v: V3<Dual<f64>>| Vector([v[0] * v[1], v[2]]), x);
+    assert_eq!([[2.0, 1.0, 0.0], [0.0, 0.0, 1.0]], j.0);
+}
+
+#[test]
+fn matrices() {
+    // d/dt det(A + tI) at t = 0 is the trace of the adjugate.
+    let m = M3([[2.0, 0.0, 1.0], [1.0, 3.0, 0.0], [0.0, 1.0, 4.0]]);
+    let t = Dual::variable(0.0);
+    let mt = M3(std::array::from_fn(|i| {
+        std::array::from_fn(|j| {
+            let d = if i == j { t } else { Dual::constant(0.0) };
+            Dual::constant(m[i][j]) + d
+        })
+    }));
+    assert_eq!(Dual::new(25.0, 12.0 + 8.0 + 6.0), mt.determinant());
+    assert_eq!(
+        vec3!(Dual::new(2.0, 0.0)),
+        Dual::constant(2.0) * vec3!(Dual::constant(1.0))
+    );
+}
@@ -0,0 +1,114 @@
+#[macro_use]
+extern crate glsl_linalg;
+
+use glsl_linalg::float::Float;
+use glsl_linalg::matrix::FloatMatrix;
+use glsl_linalg::scalar::{Fixed, Q16_16, Q32_32};
+use glsl_linalg::vector::{Cross, FloatVector};
+use glsl_linalg::{M3, V3};
+
+fn q(v: f64) -> Q16_16 {
+    Q16_16::from_f64(v)
+}
+
+#[test]
+fn arithmetic() {
+    assert_eq!(q(3.375), q(1.5) * q(2.25));
+    assert_eq!(q(-0.75), q(-1.5) * q(0.5));
+    assert_eq!(q(3.75), q(1.5) + q(2.25));
+    assert_eq!(q(-0.75), q(1.5) - q(2.25));
+    assert_eq!(q(0.5), q(2.5) % q(1.0));
+    assert_eq!(21845, (q(1.0) / q(3.0)).to_bits());
+    assert_eq!(-21845, (q(-1.0) / q(3.0)).to_bits());
+    assert_eq!(Q16_16::from_int(7), q(7.0));
+    assert_eq!(1.25, q(1.25).to_f64());
+}
+
+#[test]
+fn overflow_wraps() {
+    assert_eq!(Q16_16::MIN, Q16_16::MAX + Q16_16::EPSILON);
+    assert_eq!(Q16_16::MIN, -Q16_16::MIN);
+}
+
+#[test]
+fn abs_ceil_and_int_conversion_saturate() {
+    assert_eq!(Q16_16::MAX, Q16_16::MIN.f_abs());
+    assert_eq!(Q16_16::MAX, Q16_16::MIN.f_copysign(q(1.0)));
+    assert_eq!(Q16_16::MAX, Q16_16::MAX.f_ceil());
+    assert_eq!(q(-32768.0), Q16_16::MIN.f_ceil());
+    assert_eq!(q(-1.0), q(-1.5).f_ceil());
+    assert_eq!(q(2.0), q(1.25).f_ceil());
+    assert_eq!(Q16_16::MAX, Q16_16::f_from_i32(1 << 20));
+    assert_eq!(Q16_16::MIN, Q16_16::f_from_i32(i32::MIN));
+    assert_eq!(q(-3.0), Q16_16::f_from_i32(-3));
+    assert_eq!(
+        Q32_32::from_int(i32::MAX.into()),
+        Q32_32::f_from_i32(i32::MAX)
+    );
+}
+
+#[test]
+fn sqrt_is_rounded_to_nearest() {
+    assert_eq!(92682, q(2.0).f_sqrt().to_bits());
+    assert_eq!(207243, q(10.0).f_sqrt().to_bits());
+    assert_eq!(6_074_001_000, Q32_32::from_int(2).f_sqrt().to_bits());
+    assert_eq!(q(1.5), q(2.25).f_sqrt());
+    assert_eq!(q(0.0), q(-4.0).f_sqrt());
+    assert_eq!(
+        Q32_32::from_int(1 << 10),
+        Q32_32::from_int(1 << 20).f_sqrt()
+    );
+}
+
+#[test]
+fn custom_precision() {
+    type Q8_8 = Fixed<i32, 8>;
+    assert_eq!(Q8_8::from_bits(0x180), Q8_8::from_f64(1.5));
+    assert_eq!(
+        Q8_8::from_bits(0xff),
+        Q8_8::from_f64(1.0 / 3.0) * Q8_8::from_int(3)
+    );
+}
+
+#[test]
+fn vectors() {
+    let v = vec3!(q(3.0), q(4.0), q(0.0));
+    assert_eq!(q(5.0), v.length());
+    // Division truncates, so 3 / 5 lands one unit below the rounded 0.6.
+    assert_eq!(V3([39321, 52428, 0]), v.normalize().map(Q16_16::to_bits));
+    assert_eq!(V3([q(6.0), q(8.0), q(0.0)]), q(2.0) * v);
+    assert_eq!(
+        V3([q(0.0), q(0.0), q(1.0)]),
+        V3([q(1.0), q(0.0), q(0.0)]).cross(V3([q(0.0), q(1.0), q(0.0)]))
+    );
+}
+
+#[test]
+fn matrices() {
+    let m = M3([
+        [q(2.0), q(0.0), q(1.0)],
+        [q(1.0), q(3.0), q(0.0)],
+        [q(0.0), q(1.0), q(4.0)],
+    ]);
+    assert_eq!(q(25.0), m.determinant());
+    assert_abs_diff_eq!(
+        M3([
+            [q(1.0), q(0.0), q(0.0)],
+            [q(0.0), q(1.0), q(0.0)],
+            [q(0.0), q(0.0), q(1.0)],
+        ]),
+        m * m.inverse(),
+        epsilon = q(0.0001)
+    );
+    assert_eq!(
+        V3([q(5.0), q(7.0), q(14.0)]),
+        m * V3([q(1.0), q(2.0), q(3.0)])
+    );
+}
+
+#[test]
+fn deterministic_bits() {
+    let v = V3([q(0.1), q(0.2), q(0.3)]);
+    assert_eq!(V3([6554, 13107, 19661]), v.map(Q16_16::to_bits));
+    assert_eq!(24520, v.length().to_bits());
+}
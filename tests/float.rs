@@ -1,6 +1,8 @@
 #[macro_use]
 extern crate glsl_linalg;
 
+use glsl_linalg::float::Float;
+
 #[test]
 fn f_mul_v() {
     let f = 2.0;
@@ -16,3 +18,189 @@ fn f_mul_m() {
 
     assert_eq!(mat4!(6.0), f * m);
 }
+
+#[test]
+fn f_div_v() {
+    let f = 12.0;
+    let v = vec3!(1.0, 2.0, 3.0);
+
+    assert_eq!(vec3!(12.0, 6.0, 4.0), f / v);
+}
+
+#[test]
+fn f_add_sub_v() {
+    let v = vec2!(1, 2);
+
+    assert_eq!(vec2!(4, 5), 3 + v);
+    assert_eq!(vec2!(2, 1), 3 - v);
+    assert_eq!(vec2!(0, 1), 3 % v);
+}
+
+#[test]
+fn f_sub_m() {
+    assert_eq!(mat2!(1.0, 0.0, -1.0, -2.0), 2.0 - mat2!(1.0, 2.0, 3.0, 4.0));
+}
+
+fn ulps(a: f64, b: f64) -> u64 {
+    if a == b || (a.is_nan() && b.is_nan()) {
+        return 0;
+    }
+    let key = |v: f64| {
+        let bits = v.to_bits() as i64;
+        if bits < 0 {
+            i64::MIN - bits
+        } else {
+            bits
+        }
+    };
+    key(a).abs_diff(key(b))
+}
+
+#[test]
+fn soft_math_matches_std() {
+    use glsl_linalg::float::soft;
+
+    let xs: Vec<f64> = (-2000..=2000)
+        .map(|i| i as f64 * 0.0173)
+        .chain((-300..300).map(|i| 1.1f64.powi(i)))
+        .chain([
+            1e-300,
+            5e-324,
+            0.5,
+            1.0,
+            2.0,
+            1e5,
+            1e6,
+            1e22,
+            1e300,
+            f64::MAX,
+        ])
+        .collect();
+    for &x in &xs {
+        assert!(ulps(soft::sin(x), x.sin()) <= 1, "sin({})", x);
+        assert!(ulps(soft::cos(x), x.cos()) <= 1, "cos({})", x);
+        assert!(ulps(soft::tan(x), x.tan()) <= 1, "tan({})", x);
+        assert!(ulps(soft::atan(x), x.atan()) <= 1, "atan({})", x);
+        assert!(ulps(soft::atan2(x, 0.7), x.atan2(0.7)) <= 2, "atan2({})", x);
+        assert!(
+            ulps(soft::atan2(-0.3, x), (-0.3f64).atan2(x)) <= 2,
+            "atan2({})",
+            x
+        );
+        if x.abs() < 700.0 {
+            assert!(ulps(soft::exp(x), x.exp()) <= 1, "exp({})", x);
+        }
+        if x > 0.0 {
+            assert!(ulps(soft::log(x), x.ln()) <= 1, "log({})", x);
+            assert!(ulps(soft::pow(x, 2.5), x.powf(2.5)) <= 1, "pow({})", x);
+            assert!(ulps(soft::pow(x, -0.7), x.powf(-0.7)) <= 1, "pow({})", x);
+        }
+        let xf = x as f32;
+        if xf.is_finite() {
+            assert!(
+                (soft::sinf(xf) - xf.sin()).abs() <= f32::EPSILON,
+                "sinf({})",
+                x
+            );
+        }
+    }
+    assert_eq!(soft::powi(1.5, 7), 1.5f64.powi(7));
+    assert_eq!(soft::powi(2.0, -3), 0.125);
+    // Repeated multiplication would round at every step and drift by tens of ulps here.
+    for i in 0..10_000 {
+        let x = 1.0 + i as f64 / 10_000.0;
+        assert!(ulps(soft::powi(x, 37), x.powf(37.0)) <= 1, "powi({})", x);
+        assert!(ulps(soft::pow(x, 700.5), x.powf(700.5)) <= 1, "pow({})", x);
+    }
+}
+
+#[test]
+fn soft_math_special_values() {
+    use glsl_linalg::float::soft;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    assert_eq!(1.0, soft::exp(0.0));
+    assert_eq!(f64::INFINITY, soft::exp(710.0));
+    assert_eq!(0.0, soft::exp(-746.0));
+    assert_eq!(5e-324, soft::exp(-744.440_071_921_381_2));
+    assert_eq!(0.0, soft::exp(f64::NEG_INFINITY));
+    assert!(soft::exp(f64::NAN).is_nan());
+
+    assert_eq!(0.0, soft::log(1.0));
+    assert_eq!(f64::NEG_INFINITY, soft::log(0.0));
+    assert!(soft::log(-1.0).is_nan());
+    assert_eq!(f64::INFINITY, soft::log(f64::INFINITY));
+    assert_eq!((5e-324f64).ln(), soft::log(5e-324));
+
+    assert!(soft::sin(f64::INFINITY).is_nan());
+    assert_eq!((-0.0f64).to_bits(), soft::sin(-0.0).to_bits());
+    // The double closest to a multiple of π/2: x mod π/2 ≈ 4.687e-19 in quadrant 1.
+    let x = 6_381_956_970_095_103.0 * 2f64.powi(797);
+    assert_eq!(-4.687_165_924_254_628e-19, soft::cos(x));
+    assert_eq!(PI, soft::atan2(0.0, -1.0));
+    assert_eq!(-FRAC_PI_2, soft::atan2(-1.0, 0.0));
+    assert_eq!(FRAC_PI_2, soft::atan(f64::INFINITY));
+
+    assert_eq!(1.0, soft::pow(f64::NAN, 0.0));
+    assert_eq!(-8.0, soft::pow(-2.0, 3.0));
+    assert!(soft::pow(-2.0, 0.5).is_nan());
+    assert_eq!(f64::NEG_INFINITY, soft::pow(-0.0, -3.0));
+    assert_eq!(f64::INFINITY, soft::pow(-0.0, -2.0));
+    assert_eq!(0.0, soft::pow(0.5, f64::INFINITY));
+    assert_eq!(1.0, soft::pow(-1.0, f64::INFINITY));
+    assert_eq!(5e-324, soft::pow(2.0, -1074.0));
+    assert_eq!(f64::INFINITY, soft::pow(2.0, 1024.0));
+    assert_eq!(f64::NEG_INFINITY, soft::pow(-2.0, 1025.0));
+    assert_eq!(-0.0, soft::pow(-0.5, 1075.0));
+    assert_eq!(2f64.powi(1023), soft::powi(2.0, 1023));
+
+    assert!(soft::tan(f64::INFINITY).is_nan());
+    assert_eq!((-0.0f64).to_bits(), soft::tan(-0.0).to_bits());
+}
+
+/// Identities and a polar round trip, written once over `Float`.
+fn check_float_interface<F: Float + std::fmt::Debug>(tolerance: f64) {
+    let close = |a: F, b: F| (a - b).f_abs() <= F::f_from_f64(tolerance);
+    let n = F::f_from_i32;
+
+    assert_eq!(n(2), F::ONE + F::ONE);
+    assert_eq!(F::ONE, n(3) - n(2));
+    assert!(close(F::ZERO, F::PI.f_sin()));
+    assert!(close(F::ONE, (F::PI / n(2)).f_sin()));
+    assert!(close(F::ONE, F::ONE.f_exp().f_ln()));
+    assert!(close(F::ONE, F::ONE.f_atan() * n(4) / F::PI));
+    assert!(close(n(8), n(2).f_powi(3)));
+    assert!(close(n(8), n(4).f_powf(F::f_from_f64(1.5))));
+    assert_eq!(n(-2), F::f_from_f64(-1.5).f_floor());
+    assert_eq!(n(-1), F::f_from_f64(-1.5).f_ceil());
+    assert_eq!(-n(3), n(3).f_copysign(-F::ONE));
+    assert_eq!(n(10), n(2).f_mul_add(n(3), n(4)));
+    assert_eq!(F::ONE, F::ONE.f_min(n(2)));
+    assert_eq!(n(2), F::ONE.f_max(n(2)));
+    assert!(!F::ONE.f_is_nan() && F::ONE.f_is_finite());
+
+    let (x, y) = (n(3), n(4));
+    let (r, theta) = ((x * x + y * y).f_sqrt(), y.f_atan2(x));
+    assert_eq!(n(5), r);
+    assert!(close(x, r * theta.f_cos()) && close(y, r * theta.f_sin()));
+}
+
+#[test]
+fn float_interface() {
+    use glsl_linalg::scalar::{f16, Dual, Mediump, DD, Q16_16};
+
+    check_float_interface::<f32>(1e-6);
+    check_float_interface::<f64>(1e-14);
+    check_float_interface::<DD>(1e-30);
+    check_float_interface::<Dual<f64>>(1e-14);
+    check_float_interface::<f16>(1e-2);
+    check_float_interface::<Mediump>(1e-2);
+    check_float_interface::<Q16_16>(1e-4);
+
+    assert!(f64::NAN.f_is_nan() && !f64::INFINITY.f_is_finite());
+    assert_eq!(1.0, f64::NAN.f_max(1.0));
+    assert_eq!((-0.0f64).to_bits(), 0.0f64.f_copysign(-1.0).to_bits());
+    assert!(f16::NAN.f_is_nan() && DD::NAN.f_is_nan());
+    assert!(!Q16_16::NAN.f_is_nan());
+    assert_eq!(Q16_16::MAX, Q16_16::INFINITY);
+}
@@ -0,0 +1,89 @@
+#[macro_use]
+extern crate glsl_linalg;
+
+use glsl_linalg::approx::ApproxEq;
+use glsl_linalg::matrix::FloatMatrix;
+use glsl_linalg::scalar::f16;
+use glsl_linalg::scalar::half::{pack_half_2x16, unpack_half_2x16};
+use glsl_linalg::vector::FloatVector;
+use glsl_linalg::{M2, V2, V3, V4};
+
+fn h(v: f32) -> f16 {
+    f16::from_f32(v)
+}
+
+#[test]
+fn encoding() {
+    assert_eq!(0x3c00, h(1.0).to_bits());
+    assert_eq!(0xc000, h(-2.0).to_bits());
+    assert_eq!(0x2e66, h(0.1).to_bits());
+    assert_eq!(0x8000, h(-0.0).to_bits());
+    assert_eq!(0x7bff, h(65504.0).to_bits());
+    assert_eq!(0x7bff, h(65519.0).to_bits());
+    assert_eq!(0x7c00, h(65520.0).to_bits());
+    assert_eq!(0xfc00, h(f32::NEG_INFINITY).to_bits());
+    assert!(h(f32::NAN).is_nan());
+}
+
+#[test]
+fn subnormals_round_to_nearest_even() {
+    let tiny = 2.0f32.powi(-24);
+    assert_eq!(0x0001, h(tiny).to_bits());
+    assert_eq!(0x0000, h(tiny / 2.0).to_bits());
+    assert_eq!(0x0001, h(tiny * 0.75).to_bits());
+    assert_eq!(0x0002, h(tiny * 2.5).to_bits());
+    assert_eq!(0x0400, h(2.0f32.powi(-14)).to_bits());
+    assert_eq!(0x0400, h(2.0f32.powi(-14) - tiny / 4.0).to_bits());
+}
+
+#[test]
+fn round_trips_every_value() {
+    for bits in 0..=u16::MAX {
+        let v = f16::from_bits(bits);
+        if !v.is_nan() {
+            assert_eq!(bits, f16::from_f32(v.to_f32()).to_bits());
+            assert_eq!(bits, f16::from_f64(v.to_f64()).to_bits());
+        }
+    }
+}
+
+#[test]
+fn pack_unpack() {
+    assert_eq!(0xc000_3c00, pack_half_2x16(V2([1.0, -2.0])));
+    assert_eq!(V2([1.0, -2.0]), unpack_half_2x16(0xc000_3c00));
+    assert_eq!(V2([0.099975586, 65504.0]), unpack_half_2x16(0x7bff_2e66));
+}
+
+#[test]
+fn vector_conversions() {
+    let v = V4([0.5f32, -1.0, 0.1, 70000.0]);
+    let half = v.cast::<f16>();
+    assert_eq!(V4([0x3800, 0xbc00, 0x2e66, 0x7c00]), half.map(f16::to_bits));
+    assert_eq!(
+        V4([0.5f32, -1.0, 0.099975586, f32::INFINITY]),
+        V4::from(half)
+    );
+    assert_eq!(None, v.checked_cast::<f16>());
+    assert_eq!(f16::MAX, v.saturating_cast::<f16>()[3]);
+}
+
+#[test]
+fn arithmetic() {
+    let v = vec3!(h(3.0), h(4.0), h(0.0));
+    assert_eq!(h(5.0), v.length());
+    assert_eq!(V3([h(6.0), h(8.0), h(0.0)]), h(2.0) * v);
+    assert_eq!(h(1.0), h(1.0) + h(0.0001));
+    assert_eq!(h(1.0009766), h(1.0) + h(0.0009766));
+
+    let m = M2([[h(2.0), h(1.0)], [h(1.0), h(3.0)]]);
+    assert_eq!(h(5.0), m.determinant());
+    assert_relative_eq!(M2([[h(1.0), h(0.0)], [h(0.0), h(1.0)]]), m * m.inverse());
+}
+
+#[test]
+fn nan_is_never_approx_eq() {
+    let nan = h(f32::NAN);
+    assert!(!nan.ulps_eq(&nan, h(0.0), 4));
+    assert!(!nan.abs_diff_eq(&nan, h(1.0)));
+    assert!(h(1.0).ulps_eq(&h(1.0009766), h(0.0), 1));
+}
@@ -0,0 +1,158 @@
+#[macro_use]
+extern crate glsl_linalg;
+
+use glsl_linalg::float::Float;
+use glsl_linalg::matrix::FloatMatrix;
+use glsl_linalg::scalar::Interval;
+use glsl_linalg::{M3, M4, V4};
+
+fn i(v: f64) -> Interval<f64> {
+    Interval::f_from_f64(v)
+}
+
+#[test]
+fn outward_rounding() {
+    let tenth = i(0.1);
+    let sum = tenth + tenth + tenth;
+    assert!(sum.lo() < sum.hi());
+    assert!(sum.contains(0.1 + 0.1 + 0.1));
+    assert!(sum.lo() <= 0.30000000000000004 && 0.3 <= sum.hi());
+
+    let third = i(1.0) / i(3.0);
+    assert!(third.lo() < 1.0 / 3.0 && 1.0 / 3.0 < third.hi());
+    assert_eq!(third.lo().next_up().next_up(), third.hi());
+
+    let x = Interval::new(-1.0, 2.0);
+    assert!((x * x).contains(-2.0) && (x * x).contains(4.0));
+    assert_eq!(Interval::new(-2.0, 1.0), -x);
+    assert_eq!(Interval::new(0.0, 2.0), x.f_abs());
+    assert_eq!(Interval::entire(), i(1.0) / x);
+
+    let root = i(2.0).f_sqrt();
+    assert!(root.contains(2f64.sqrt()));
+    assert!(root.lo() * root.lo() < 2.0 && 2.0 < root.hi() * root.hi());
+
+    let f = Interval::<f32>::f_from_f64(0.1);
+    assert!(f64::from(f.lo()) < 0.1 && 0.1 < f64::from(f.hi()));
+}
+
+#[test]
+fn ordering() {
+    assert!(i(1.0) < i(2.0));
+    assert!(Interval::new(0.0, 1.0) < Interval::new(1.5, 2.0));
+    assert_eq!(
+        None,
+        Interval::new(0.0, 2.0).partial_cmp(&Interval::new(1.0, 3.0))
+    );
+    assert!(Interval::new(0.0, 1.0) <= Interval::new(0.0, 1.0));
+}
+
+#[test]
+fn matrix_vector_enclosure() {
+    let m = M4([
+        [0.1, 0.2, 0.3, 0.4],
+        [1.0 / 3.0, 2.0 / 3.0, 1.0, 4.0 / 3.0],
+        [1e16, 1.0, -1e16, 1.0],
+        [0.7, -0.1, 0.0, 2.5],
+    ]);
+    let v = V4([3.0, -1.0 / 7.0, 1.0, 0.3]);
+    let exact = m * v;
+    let enclosed = m.map(i) * v.map(i);
+    for k in 0..4 {
+        assert!(enclosed[k].contains(exact[k]));
+        assert!(enclosed[k].width() > 0.0);
+    }
+    // 1e16 * 3 + 1e16 * -1 cancels, but the rounded terms leave a visible error bound.
+    assert!(enclosed[2].width() >= 2.0);
+}
+
+#[test]
+fn reliable_determinant_sign() {
+    let m = M3([[2.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 4.0]]).map(i);
+    let det = m.determinant();
+    assert!(det.contains(18.0));
+    assert!(!det.contains(0.0));
+    assert!(det > i(0.0));
+
+    let singular = M3([[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.7, 0.8, 0.9]]).map(i);
+    assert!(singular.determinant().contains(0.0));
+
+    // `1 / [-1, 1]` is entire, and the zero entries multiply it by `0` rather than giving NaN.
+    let unbounded = i(1.0) / Interval::new(-1.0, 1.0);
+    let m = M3([
+        [unbounded, i(0.0), i(0.0)],
+        [i(0.0), i(1.0), i(0.0)],
+        [i(0.0), i(0.0), i(1.0)],
+    ]);
+    let det = m.determinant();
+    assert!(!det.f_is_nan());
+    assert!(det.contains(0.0) && det.contains(1e300));
+}
+
+#[test]
+fn unbounded_products() {
+    let unit = Interval::new(0.0, 1.0);
+    let product = Interval::entire() * unit;
+    assert!(!product.f_is_nan());
+    assert!(product.contains(0.0) && product.contains(-1e300) && product.contains(1e300));
+    assert!((i(0.0) * Interval::entire()).contains(0.0));
+
+    let quotient = Interval::new(1.0, f64::INFINITY) / Interval::new(1.0, f64::INFINITY);
+    assert!(quotient.contains(0.5) && quotient.contains(1e300));
+    assert!((Interval::<f64>::NAN * unit).f_is_nan());
+}
+
+#[test]
+fn vector_ops() {
+    let v = vec3!(1.0, 2.0, 3.0).map(i);
+    let doubled = i(2.0) * v;
+    for k in 0..3 {
+        assert!(doubled[k].contains(2.0 * (k + 1) as f64));
+    }
+    assert!((v / i(3.0))[0].contains(1.0 / 3.0));
+}
+
+#[test]
+fn transcendental_enclosure() {
+    use std::f64::consts::{E, PI};
+
+    let tight = |r: Interval<f64>, v: f64| {
+        r.contains(v) && r.lo().next_up().next_up().next_up().next_up() >= r.hi()
+    };
+    for x in [0.1, 0.5, 1.0, 2.0, 3.0, 10.0, -7.25] {
+        assert!(tight(i(x).f_exp(), x.exp()), "exp({})", x);
+        assert!(tight(i(x).f_sin(), x.sin()), "sin({})", x);
+        assert!(tight(i(x).f_cos(), x.cos()), "cos({})", x);
+        assert!(tight(i(x).f_atan(), x.atan()), "atan({})", x);
+        if x > 0.0 {
+            assert!(tight(i(x).f_ln(), x.ln()), "ln({})", x);
+        }
+    }
+    assert!(Interval::<f64>::PI.contains(PI) && Interval::<f64>::PI.lo() < PI.next_up());
+    assert!(i(1.0).f_exp().contains(E));
+
+    // Extrema inside the interval are exact.
+    let r = Interval::new(0.0, 4.0).f_sin();
+    assert_eq!(1.0, r.hi());
+    assert!(r.lo() <= 4f64.sin() && r.lo() > -0.76);
+    assert_eq!(-1.0, Interval::new(3.0, 3.5).f_cos().lo());
+    assert_eq!(1.0, Interval::new(-1.0, 1.0).f_cos().hi());
+    assert_eq!(Interval::new(-1.0, 1.0), Interval::new(0.0, 10.0).f_cos());
+    assert_eq!(Interval::entire(), Interval::new(1.0, 2.0).f_tan());
+
+    let (lo, hi) = (Interval::new(1.0, 2.0), Interval::new(-1.0, -0.5));
+    let a = lo.f_atan2(lo);
+    assert!(a.contains(0.5f64.atan()) && a.contains(2f64.atan()));
+    assert!(a.lo() > 0.46 && a.hi() < 1.11);
+    // Crossing the negative x axis covers the whole circle.
+    let a = Interval::new(-1.0, 1.0).f_atan2(hi);
+    assert!(a.contains(-PI) && a.contains(PI));
+
+    assert_eq!(-f64::INFINITY, Interval::new(0.0, 1.0).f_ln().lo());
+    assert!(Interval::new(-2.0, -1.0).f_ln().f_is_nan());
+    assert_eq!(Interval::new(-2.0, 1.0), Interval::new(-1.5, 1.5).f_floor());
+    assert_eq!(
+        Interval::new(-2.0, 2.0),
+        Interval::new(1.0, 2.0).f_copysign(Interval::new(-1.0, 1.0))
+    );
+}
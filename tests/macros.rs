@@ -47,6 +47,7 @@ fn mat2() {
         M2([[1.0, 2.0], [3.0, 4.0]]),
         mat2!(1.0, 2.0; vec2!(3.0, 4.0))
     );
+    assert_eq!(M2([[1, 2], [3, 3]]), mat2!(1, 2; 3));
     assert_eq!(
         M2([[1.0, 2.0], [3.0, 4.0]]),
         mat2!(vec2!(1.0, 2.0); 3.0, 4.0)
@@ -67,6 +68,10 @@ fn mat3() {
         M3([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]),
         mat3!(1.0, 2.0, 3.0; 4.0, 5.0, 6.0; 7.0, 8.0, 9.0)
     );
+    assert_eq!(
+        M3([[1, 2, 3], [4, 4, 4], [5, 6, 7]]),
+        mat3!(1, 2, 3; 4; 5, 6, 7)
+    );
     assert_eq!(
         M3([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]),
         mat3!(1.0, 2.0, 3.0; 4.0, 5.0, 6.0; 7.0, 8.0, 9.0)
@@ -144,3 +149,74 @@ fn mat4() {
         mat4!(vec4!(1.0, 2.0, 3.0, 4.0); vec2!(5.0, 6.0), 7.0, 8.0; 9.0, vec3!(10.0, 11.0, 12.0); vec2!(13.0, 14.0), vec2!(15.0, 16.0))
     );
 }
+
+#[test]
+fn implicit_promotion() {
+    let ivec2: V2<i32> = vec2!(1, 2);
+    assert_eq!(V4([1.0, 2.0, 1.0, 0.0]), vec4!(ivec2, 1.0, 0.0));
+    assert_eq!(V3([1.0, 2.0, 3.0]), vec3!(1, 2.0, 3u8));
+    assert_eq!(V3([1.0f32, 2.5, 3.0]), vec3!(1, 2.5f32, 3));
+    assert_eq!(V3([0.5, 2.0, 3.0]), vec3!(0.5, vec2!(2u16, 3u16)));
+    assert_eq!(M2([[1.0, 2.0], [3.0, 4.0]]), mat2!(1, 2.0; 3u8, 4.0));
+}
+
+#[test]
+fn literals_infer_from_context() {
+    let v: V3<f32> = vec3!(1.0, 2.0, 3.0);
+    assert_eq!(V3([1.0f32, 2.0, 3.0]), v);
+    let v: V4<i64> = vec4!(1, vec2!(2, 3), 4);
+    assert_eq!(V4([1i64, 2, 3, 4]), v);
+    let v: V2<f32> = vec2!(vec3!(1.0, 2.0, 3.0));
+    assert_eq!(V2([1.0f32, 2.0]), v);
+}
+
+#[test]
+fn glsl_mat_diagonal() {
+    assert_eq!(M2([[2.0, 0.0], [0.0, 2.0]]), glsl_mat2!(2.0));
+    assert_eq!(
+        M4([[1, 0, 0, 0], [0, 1, 0, 0], [0, 0, 1, 0], [0, 0, 0, 1]]),
+        glsl_mat4!(1)
+    );
+}
+
+#[test]
+fn glsl_mat_column_major() {
+    assert_eq!(M2([[1.0, 3.0], [2.0, 4.0]]), glsl_mat2!(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(
+        M2([[1.0, 3.0], [2.0, 4.0]]),
+        glsl_mat2!(vec2!(1.0, 2.0), vec2!(3.0, 4.0))
+    );
+    assert_eq!(
+        M2([[1.0, 3.0], [2.0, 4.0]]),
+        glsl_mat2!(vec4!(1.0, 2.0, 3.0, 4.0))
+    );
+    assert_eq!(
+        M3([[1, 4, 7], [2, 5, 8], [3, 6, 9]]),
+        glsl_mat3!(vec3!(1, 2, 3), 4, vec2!(5, 6), vec3!(7, 8, 9))
+    );
+}
+
+#[test]
+fn glsl_mat_promotion() {
+    assert_eq!(M2([[1.0, 0.0], [0.5, 1.0]]), glsl_mat2!(1, 0.5, 0, 1));
+    assert_eq!(
+        M2([[1.0f32, 3.0], [2.0, 4.0]]),
+        glsl_mat2!(vec3!(1.0f32, 2.0, 3.0), vec2!(4, 5))
+    );
+}
+
+#[test]
+fn glsl_mat_resize() {
+    let m4 = M4([
+        [1, 2, 3, 4],
+        [5, 6, 7, 8],
+        [9, 10, 11, 12],
+        [13, 14, 15, 16],
+    ]);
+    assert_eq!(M3([[1, 2, 3], [5, 6, 7], [9, 10, 11]]), glsl_mat3!(m4));
+    assert_eq!(
+        M4([[1, 2, 0, 0], [3, 4, 0, 0], [0, 0, 1, 0], [0, 0, 0, 1]]),
+        glsl_mat4!(M2([[1, 2], [3, 4]]))
+    );
+    assert_eq!(m4, glsl_mat4!(m4));
+}
@@ -1,7 +1,10 @@
 #[macro_use]
 extern crate glsl_linalg;
 
-use glsl_linalg::matrix::{FloatMatrix, Matrix};
+use glsl_linalg::matrix::{FloatMatrix, Matrix, MatrixFunction};
+use glsl_linalg::numeric::Identity;
+use glsl_linalg::vector::Vector;
+use glsl_linalg::{M2, M3, M4, V2, V3};
 
 #[test]
 fn m_div_f() {
@@ -83,3 +86,366 @@ fn m_inverse() {
 
     assert_eq!(mat2!(1.0, -2.0, 0.0, 1.0), m.inverse());
 }
+
+#[test]
+fn m_polar_decompose() {
+    let (c, s) = (0.6, 0.8);
+    let r = mat3!(c, -s, 0.0, s, c, 0.0, 0.0, 0.0, 1.0);
+    let stretch = mat3!(2.0, 0.5, 0.0, 0.5, 1.0, 0.25, 0.0, 0.25, 3.0);
+    let (rr, ss) = (r * stretch).polar_decompose();
+
+    assert_abs_diff_eq!(r, rr, epsilon = 1e-12);
+    assert_abs_diff_eq!(stretch, ss, epsilon = 1e-12);
+    assert_abs_diff_eq!(r * stretch, rr * ss, epsilon = 1e-12);
+}
+
+#[test]
+fn m_polar_decompose_near_singular() {
+    let m = mat3!(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0 + 1e-12);
+    let (r, s) = m.polar_decompose();
+    let mut rt = r;
+    rt.transpose();
+
+    assert_abs_diff_eq!(
+        mat3!(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0),
+        rt * r,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(m, r * s, epsilon = 1e-12);
+    assert_abs_diff_eq!(-1.0, r.determinant(), epsilon = 1e-12);
+
+    let (r, s) = mat3!(0.0).polar_decompose();
+    assert_abs_diff_eq!(
+        mat3!(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0),
+        r,
+        epsilon = 0.0
+    );
+    assert_abs_diff_eq!(mat3!(0.0), s, epsilon = 0.0);
+}
+
+#[test]
+fn m_closest_rotation() {
+    let m: M3<f64> = mat3!(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, -1.0);
+    let (q, _) = m.polar_decompose();
+    assert_abs_diff_eq!(-1.0, q.determinant(), epsilon = 1e-12);
+
+    let r: M3<f64> = mat3!(2.0, 0.1, 0.0, -0.1, 1.0, 0.0, 0.0, 0.0, -0.5).closest_rotation();
+    let mut rt = r;
+    rt.transpose();
+
+    assert_abs_diff_eq!(1.0, r.determinant(), epsilon = 1e-12);
+    assert_abs_diff_eq!(
+        mat3!(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0),
+        rt * r,
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn m_mul_f() {
+    let m = mat2!(1, 2, 3, 4);
+
+    assert_eq!(mat2!(3, 6, 9, 12), m * 3);
+}
+
+#[test]
+fn m_exp() {
+    assert_abs_diff_eq!(M3::identity(), mat3!(0.0).exp(), epsilon = 0.0);
+
+    let e = mat3!(1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, -3.0).exp();
+    let (e1, e2, e3) = (1f64.exp(), 2f64.exp(), (-3f64).exp());
+    assert_abs_diff_eq!(
+        mat3!(e1, 0.0, 0.0, 0.0, e2, 0.0, 0.0, 0.0, e3),
+        e,
+        epsilon = 1e-12
+    );
+
+    let t = std::f64::consts::FRAC_PI_3;
+    let r = mat3!(0.0, -t, 0.0, t, 0.0, 0.0, 0.0, 0.0, 0.0).exp();
+    assert_abs_diff_eq!(
+        mat3!(t.cos(), -t.sin(), 0.0, t.sin(), t.cos(), 0.0, 0.0, 0.0, 1.0),
+        r,
+        epsilon = 1e-14
+    );
+
+    let e = 1f64.exp();
+    assert_abs_diff_eq!(
+        mat2!(e, 2.0 * e, 0.0, e),
+        mat2!(1.0, 2.0, 0.0, 1.0).exp(),
+        epsilon = 1e-14
+    );
+
+    let n = mat4!(0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 0.0);
+    assert_eq!(M4::identity() + n, n.exp());
+}
+
+#[test]
+fn m_log() {
+    let m = mat3!(4.0, 1.0, 0.0, 0.5, 3.0, 0.2, 0.0, 0.1, 2.0);
+
+    assert_abs_diff_eq!(m, m.log().exp(), epsilon = 1e-12);
+    assert_abs_diff_eq!(mat3!(0.0), M3::<f64>::identity().log(), epsilon = 1e-15);
+
+    let a = mat3!(0.1, -0.7, 0.2, 0.7, 0.3, 0.0, -0.2, 0.0, -0.4);
+    assert_abs_diff_eq!(a, a.exp().log(), epsilon = 1e-12);
+}
+
+#[test]
+fn m_sqrt() {
+    let m = mat3!(4.0, 1.0, 0.0, 0.5, 3.0, 0.2, 0.0, 0.1, 2.0);
+    let s = m.sqrt();
+
+    assert_abs_diff_eq!(m, s * s, epsilon = 1e-12);
+    assert_abs_diff_eq!(s, m.powf(0.5), epsilon = 1e-12);
+}
+
+#[test]
+fn m_pow() {
+    let m = mat3!(2.0, -1.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 2.0);
+
+    assert_abs_diff_eq!(M3::identity(), m.powi(0), epsilon = 0.0);
+    assert_abs_diff_eq!(m * m * m, m.powi(3), epsilon = 0.0);
+    assert_abs_diff_eq!(m.inverse() * m.inverse(), m.powi(-2), epsilon = 1e-12);
+
+    let p = mat3!(4.0, 1.0, 0.0, 0.5, 3.0, 0.2, 0.0, 0.1, 2.0);
+    assert_abs_diff_eq!(p * p * p, p.powf(3.0), epsilon = 1e-10);
+}
+
+#[test]
+fn m_generic_size() {
+    let a = Matrix([[1, 2, 3], [4, 5, 6]]);
+    let b = Matrix([[1, 0], [0, 1], [1, 1]]);
+
+    assert_eq!(Matrix([[4, 5], [10, 11]]), a * b);
+    assert_eq!(Matrix([[1, 4], [2, 5], [3, 6]]), a.transposed());
+    assert_eq!(
+        Matrix([[true, true], [true, true], [false, false]]),
+        a.map(|e| e % 3 != 0).transposed()
+    );
+    assert_eq!(Vector([14, 32]), a * Vector([1, 2, 3]));
+    assert_eq!(Vector([9, 12, 15]), Vector([1, 2]) * a);
+}
+
+#[test]
+fn m_assign_ops() {
+    let mut m = mat2!(1, 2, 3, 4);
+
+    m += mat2!(1);
+    assert_eq!(mat2!(2, 3, 4, 5), m);
+    m -= 1;
+    assert_eq!(mat2!(1, 2, 3, 4), m);
+    m *= mat2!(-2, 1, 3, 1);
+    assert_eq!(mat2!(4, 3, 6, 7), m);
+    m *= 2;
+    assert_eq!(mat2!(8, 6, 12, 14), m);
+    m /= 2;
+    assert_eq!(mat2!(4, 3, 6, 7), m);
+    m %= 3;
+    assert_eq!(mat2!(1, 0, 0, 1), m);
+
+    let mut m = mat2!(8, 6, 12, 14);
+    m /= mat2!(2, 3, 4, 7);
+    assert_eq!(mat2!(4, 2, 3, 2), m);
+    m %= mat2!(3, 2, 2, 3);
+    assert_eq!(mat2!(1, 0, 1, 2), m);
+    assert_eq!(
+        mat2!(0.5, 2.0, 1.5, 4.0),
+        mat2!(1.0, 4.0, 3.0, 8.0) / mat2!(2.0)
+    );
+}
+
+#[test]
+fn m_neg() {
+    assert_eq!(mat2!(-1, 2, -3, 4), -mat2!(1, -2, 3, -4));
+}
+
+#[test]
+fn m_add_f() {
+    assert_eq!(mat2!(2, 3, 4, 5), mat2!(1, 2, 3, 4) + 1);
+    assert_eq!(mat2!(0, 1, 2, 3), mat2!(1, 2, 3, 4) - 1);
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn m_ref_ops() {
+    let m1 = mat2!(1, 2, 3, 4);
+    let m2 = mat2!(-2, 1, 3, 1);
+    let v = vec2!(1, 1);
+
+    assert_eq!(mat2!(4, 3, 6, 7), &m1 * &m2);
+    assert_eq!(mat2!(-1, 3, 6, 5), &m1 + m2);
+    assert_eq!(mat2!(3, 1, 0, 3), m1 - &m2);
+    assert_eq!(mat2!(2, 4, 6, 8), &m1 * 2);
+    assert_eq!(mat2!(-1, -2, -3, -4), -&m1);
+    assert_eq!(vec2!(3, 7), &m1 * &v);
+    assert_eq!(vec2!(3, 7), m1 * &v);
+}
+
+#[test]
+fn m_sum_product() {
+    let ms = [mat2!(1, 2, 3, 4), mat2!(-2, 1, 3, 1), mat2!(1, 0, 0, 2)];
+
+    assert_eq!(mat2!(0, 3, 6, 7), ms.iter().sum());
+    assert_eq!(mat2!(4, 6, 6, 14), ms.iter().product());
+    assert_eq!(
+        mat2!(1, 0, 0, 1),
+        Vec::<M2<i32>>::new().into_iter().product()
+    );
+}
+
+#[test]
+fn m_iter() {
+    let mut m = mat2!(1, 2, 3, 4);
+
+    assert_eq!(
+        vec![1, 2, 3, 4],
+        m.iter_elements().copied().collect::<Vec<_>>()
+    );
+    assert_eq!(vec![[1, 2], [3, 4]], m.iter().copied().collect::<Vec<_>>());
+    for e in &mut m {
+        *e *= 2;
+    }
+    assert_eq!(vec![2, 4, 6, 8], m.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn m_try_from_iter() {
+    assert_eq!(Some(mat2!(0, 1, 2, 3)), M2::try_from_iter(0..4));
+    assert_eq!(
+        Some(Matrix([[0, 1, 2], [3, 4, 5]])),
+        Matrix::try_from_iter(0..6)
+    );
+    assert_eq!(None, M2::try_from_iter(0..3));
+    assert_eq!(None, M2::try_from_iter(0..5));
+    assert_eq!(None, M2::try_from_iter(std::iter::repeat(1)));
+    assert_eq!(
+        None,
+        Matrix::<i32, 2, 3>::try_from_iter((0..6).filter(|&i| i != 2))
+    );
+    assert_eq!(Some(mat2!(0, 1, 2, 3)), M2::try_from_iter(exhaustible(4)));
+    assert_eq!(None, M3::<i32>::try_from_iter(exhaustible(4)));
+}
+
+/// Yields `0..n`, then panics if it is polled again after returning `None`.
+fn exhaustible(n: i32) -> impl Iterator<Item = i32> {
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        assert!(i <= n, "polled after returning None");
+        i += 1;
+        if i <= n {
+            Some(i - 1)
+        } else {
+            None
+        }
+    })
+}
+
+#[test]
+fn m_map() {
+    let m = mat2!(1.5, -2.5, 3.0, 0.5);
+
+    assert_eq!(mat2!(1, -2, 3, 0), m.map(|e| e as i32));
+    assert_eq!(
+        mat2!(3.0, -5.0, 3.0, 2.0),
+        m.zip_with(mat2!(2.0, 2.0, 1.0, 4.0), |a, b| a * b)
+    );
+}
+
+#[test]
+fn m_fold() {
+    let m = mat3!(1, 2, 3, 4, 5, 6, 7, 8, -9);
+
+    assert_eq!(
+        vec![1, 2, 3],
+        m.fold(Vec::new(), |mut v, e| {
+            if v.len() < 3 {
+                v.push(e);
+            }
+            v
+        })
+    );
+    assert_eq!(-9, m.min_element());
+    assert_eq!(8, m.max_element());
+    assert_eq!(27, m.sum_elements());
+    assert_eq!(-362880, m.product_elements());
+    assert_eq!(8, m.reduce(|a, b| if a > b { a } else { b }));
+}
+
+#[test]
+fn m_integer_determinant() {
+    assert_eq!(Some(-2i64), mat2!(1, 2, 3, 4).checked_determinant());
+    assert_eq!(
+        Some(0i64),
+        mat3!(1, 2, 3, 4, 5, 6, 7, 8, 9).checked_determinant()
+    );
+    assert_eq!(
+        Some(-1i32),
+        mat3!(0, 1, 0, 1, 0, 0, 0, 0, 1).checked_determinant()
+    );
+    assert_eq!(
+        Some(8i32),
+        mat4!(1, 2, -1, 2, 0, 1, 0, 2, 2, 3, 4, 0, 1, 2, 0, 3).checked_determinant()
+    );
+    assert_eq!(None, mat2!(100i8, 0, 0, 100).checked_determinant());
+    assert_eq!(None, mat2!(i64::MAX, 0, 0, 2).checked_determinant());
+}
+
+#[test]
+fn m_integer_adjugate() {
+    let m = mat3!(2i64, 0, 1, 1, 3, 2, 1, 1, 1);
+    let adj = m.checked_adjugate().unwrap();
+    let det = m.checked_determinant().unwrap();
+    assert_eq!(mat3!(det, 0, 0, 0, det, 0, 0, 0, det), m * adj);
+
+    let singular = mat3!(1i64, 2, 3, 2, 4, 6, 1, 0, 1);
+    assert_eq!(
+        M3([[0; 3]; 3]),
+        singular * singular.checked_adjugate().unwrap()
+    );
+    assert_eq!(
+        mat2!(4i64, -2, -3, 1),
+        mat2!(1i64, 2, 3, 4).checked_adjugate().unwrap()
+    );
+}
+
+#[test]
+fn m_integer_inverse() {
+    let (inverse, denominator) = mat2!(1i32, 2, 3, 4).rational_inverse().unwrap();
+    assert_eq!((mat2!(-4, 2, 3, -1), 2), (inverse, denominator));
+
+    let m = mat2!(2i32, 0, 0, 2);
+    assert_eq!(Some((mat2!(1, 0, 0, 1), 2)), m.rational_inverse());
+    assert_eq!(None, m.checked_inverse());
+
+    let shear = mat3!(1i64, 2, 0, 0, 1, 0, 0, 0, -1);
+    let inverse = shear.checked_inverse().unwrap();
+    assert_eq!(mat3!(1, -2, 0, 0, 1, 0, 0, 0, -1), inverse);
+    assert_eq!(mat3!(1, 0, 0, 0, 1, 0, 0, 0, 1), shear * inverse);
+
+    assert_eq!(None, mat3!(1i64, 2, 3, 4, 5, 6, 7, 8, 9).rational_inverse());
+}
+
+#[test]
+fn m_const() {
+    const BASIS: M3<f32> = mat3!(const V3::Y; V3::Z; vec3!(const 1.0, 0.0, 0.0));
+    const INVERSE: M3<f32> = BASIS.const_transposed();
+    const V: V3<f32> = BASIS.const_mul_vector(vec3!(const 1.0, 2.0, 3.0));
+    const S: M2<i32> = {
+        let mut m = mat2!(1, 2, 3, 4);
+        m.transpose();
+        m
+    };
+    const T: M2<i32> = mat2!(const vec2!(const 1i32); V2::Y)
+        .const_add(M2::IDENTITY)
+        .const_scale(2)
+        .const_sub(M2::splat(1))
+        .const_neg();
+
+    assert_eq!(M3::IDENTITY, BASIS.const_mul(INVERSE));
+    assert_eq!(BASIS * vec3!(1.0, 2.0, 3.0), V);
+    assert_eq!(mat2!(1, 3, 2, 4), S);
+    assert_eq!(mat2!(-3, -1, 1, -3), T);
+    assert_eq!(BASIS.col(2), INVERSE.row(2));
+    assert_eq!(BASIS.transposed(), INVERSE);
+    assert_eq!(1.0, BASIS.element(2, 0));
+}
@@ -0,0 +1,170 @@
+#[macro_use]
+extern crate glsl_linalg;
+
+use glsl_linalg::numeric::{Identity, Numeric, One, Zero};
+use glsl_linalg::vector::Cross;
+use glsl_linalg::{M2, M3, M4, V2, V3, V4};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// A downstream scalar with the full operator set.
+#[derive(Copy, Clone, Default, PartialEq, Debug)]
+struct Meters(f64);
+
+macro_rules! impl_meters_op {
+    ($op: ident, $f: ident) => {
+        impl $op for Meters {
+            type Output = Meters;
+
+            fn $f(self, rhs: Meters) -> Meters {
+                Meters(self.0.$f(rhs.0))
+            }
+        }
+    };
+}
+
+impl_meters_op!(Add, add);
+impl_meters_op!(Sub, sub);
+impl_meters_op!(Mul, mul);
+impl_meters_op!(Div, div);
+impl_meters_op!(Rem, rem);
+
+impl Neg for Meters {
+    type Output = Meters;
+
+    fn neg(self) -> Meters {
+        Meters(-self.0)
+    }
+}
+
+impl Zero for Meters {
+    const ZERO: Meters = Meters(0.0);
+}
+
+impl One for Meters {
+    const ONE: Meters = Meters(1.0);
+}
+
+impl Numeric for Meters {}
+
+impl_scalar_ops!(Meters);
+
+/// A generic downstream scalar without `Rem`.
+#[derive(Copy, Clone, Default, PartialEq, Debug)]
+struct Wrapper<T>(T);
+
+impl<T: Numeric> Add for Wrapper<T> {
+    type Output = Wrapper<T>;
+
+    fn add(self, rhs: Wrapper<T>) -> Wrapper<T> {
+        Wrapper(self.0 + rhs.0)
+    }
+}
+
+impl<T: Numeric> Sub for Wrapper<T> {
+    type Output = Wrapper<T>;
+
+    fn sub(self, rhs: Wrapper<T>) -> Wrapper<T> {
+        Wrapper(self.0 - rhs.0)
+    }
+}
+
+impl<T: Numeric> Mul for Wrapper<T> {
+    type Output = Wrapper<T>;
+
+    fn mul(self, rhs: Wrapper<T>) -> Wrapper<T> {
+        Wrapper(self.0 * rhs.0)
+    }
+}
+
+impl<T: Numeric> Div for Wrapper<T> {
+    type Output = Wrapper<T>;
+
+    fn div(self, rhs: Wrapper<T>) -> Wrapper<T> {
+        Wrapper(self.0 / rhs.0)
+    }
+}
+
+impl<T: Numeric> Neg for Wrapper<T> {
+    type Output = Wrapper<T>;
+
+    fn neg(self) -> Wrapper<T> {
+        Wrapper(-self.0)
+    }
+}
+
+impl<T: Numeric> Zero for Wrapper<T> {
+    const ZERO: Wrapper<T> = Wrapper(T::ZERO);
+}
+
+impl<T: Numeric> One for Wrapper<T> {
+    const ONE: Wrapper<T> = Wrapper(T::ONE);
+}
+
+impl<T: Numeric> Numeric for Wrapper<T> {}
+
+impl_scalar_op!([T: Numeric] Wrapper<T>, Mul, mul);
+impl_scalar_op!([T: Numeric] Wrapper<T>, Sub, sub);
+
+#[test]
+fn left_scalar_ops() {
+    let v = V3([Meters(1.0), Meters(2.0), Meters(3.0)]);
+    let s = Meters(2.0);
+    assert_eq!(v * s, s * v);
+    assert_eq!(V3([Meters(3.0), Meters(4.0), Meters(5.0)]), s + v);
+    assert_eq!(V3([Meters(1.0), Meters(0.0), Meters(-1.0)]), s - v);
+    assert_eq!(V3([Meters(2.0), Meters(1.0), Meters(2.0 / 3.0)]), s / v);
+    assert_eq!(V3([Meters(0.0), Meters(0.0), Meters(2.0)]), s % v);
+
+    let m = M2([[Meters(1.0), Meters(2.0)], [Meters(3.0), Meters(4.0)]]);
+    assert_eq!(m * s, s * m);
+    assert_eq!(
+        M2([[Meters(1.0), Meters(0.0)], [Meters(-1.0), Meters(-2.0)]]),
+        s - m
+    );
+}
+
+#[test]
+fn generic_left_scalar_ops() {
+    let v = vec3!(1, 2, 3).map(Wrapper);
+    assert_eq!(vec3!(2, 4, 6).map(Wrapper), Wrapper(2) * v);
+    assert_eq!(vec3!(0, -1, -2).map(Wrapper), Wrapper(1) - v);
+    assert_eq!(
+        mat2!(3, 6, 9, 12).map(Wrapper),
+        Wrapper(3) * mat2!(1, 2, 3, 4).map(Wrapper)
+    );
+}
+
+/// `Σ x^k / k!` for `T: Numeric`, which needs a `1` to start from.
+fn exp_series<T: Numeric>(x: T, terms: i32) -> T {
+    let (mut sum, mut term, mut k) = (T::ZERO, T::ONE, T::ONE);
+    for _ in 0..terms {
+        sum = sum + term;
+        term = term * x / k;
+        k = k + T::ONE;
+    }
+    sum
+}
+
+#[test]
+fn identities() {
+    assert_eq!(vec3!(0, 0, 0), V3::ZERO);
+    assert_eq!(vec3!(1.0, 1.0, 1.0), V3::ONE);
+    assert_eq!(vec2!(0, 1), V2::Y);
+    assert_eq!(vec3!(0.0, 0.0, 1.0), V3::Z);
+    assert_eq!(vec4!(0, 0, 0, 1), V4::W);
+    assert_eq!(V3::<i32>::Z, V3::X.cross(V3::Y));
+    assert_eq!(mat3!(1, 0, 0, 0, 1, 0, 0, 0, 1), M3::IDENTITY);
+    assert_eq!(mat4!(0.0), M4::ZERO);
+
+    let m = mat4!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+    assert_eq!(m, m * M4::IDENTITY);
+    assert_eq!(m, M4::IDENTITY * m);
+    assert_eq!(M2::IDENTITY, std::iter::empty::<M2<Meters>>().product());
+    assert_eq!(V2::ONE, std::iter::empty::<V2<Wrapper<i32>>>().product());
+
+    const BASIS: [V3<f32>; 3] = [V3::X, V3::Y, V3::Z];
+    assert_eq!(M3::IDENTITY, M3(BASIS.map(|v| v.0)));
+
+    assert_eq!(Meters(2.5), exp_series(Meters(1.0), 3));
+    assert_eq!(5.0, exp_series(2.0, 3));
+}
@@ -0,0 +1,57 @@
+extern crate glsl_linalg;
+
+use glsl_linalg::float::Float;
+use glsl_linalg::scalar::{Lowp, Mediump};
+use glsl_linalg::vector::FloatVector;
+use glsl_linalg::V3;
+
+#[test]
+fn mediump_rounding() {
+    let one = Mediump::new(1.0);
+    assert_eq!(one, one + Mediump::new(1.0 / 4096.0));
+    assert_eq!(1.0 + 1.0 / 1024.0, (one + Mediump::EPSILON).to_f32());
+    assert_eq!(2048.0, (Mediump::new(2048.0) + one).to_f32());
+    assert_eq!(0.099975586, Mediump::new(0.1).to_f32());
+    assert_eq!(65504.0, Mediump::new(65519.0).to_f32());
+    assert!(Mediump::new(65520.0).to_f32().is_infinite());
+}
+
+#[test]
+fn from_f64_rounds_once() {
+    // Just above a tie, but rounding to `f32` first lands on the tie, which goes to even.
+    let above_tie = 1.0 + 2f64.powi(-11) + 2f64.powi(-40);
+    assert_eq!(1.0 + 1.0 / 1024.0, Mediump::f_from_f64(above_tie).to_f32());
+    assert_eq!(1.0, Mediump::new(above_tie as f32).to_f32());
+
+    let above_tie = 2f64.powi(-9) + 2f64.powi(-40);
+    assert_eq!(1.0 / 256.0, Lowp::f_from_f64(above_tie).to_f32());
+    assert_eq!(0.0, Lowp::new(above_tie as f32).to_f32());
+}
+
+#[test]
+fn mediump_vector_overflow() {
+    // The intermediate dot product exceeds the f16 range even though the length does not.
+    let v = V3([300.0, 300.0, 0.0]).cast::<Mediump>();
+    assert!(v.length().to_f32().is_infinite());
+    assert!(v.normalize()[0] == Mediump::default());
+
+    let small = V3([3.0, 4.0, 0.0]).cast::<Mediump>();
+    assert_eq!(Mediump::new(5.0), small.length());
+    assert_eq!(V3([0.6, 0.8, 0.0]).cast::<Mediump>(), small.normalize());
+}
+
+#[test]
+fn lowp_banding() {
+    assert_eq!(26.0 / 256.0, Lowp::new(0.1).to_f32());
+    assert_eq!(Lowp::new(0.0), Lowp::new(0.001));
+    assert_eq!(2.0 - 1.0 / 256.0, Lowp::new(5.0).to_f32());
+    assert_eq!(-2.0, Lowp::new(-5.0).to_f32());
+    assert_eq!(Lowp::new(1.5), Lowp::new(1.0) + Lowp::new(0.5));
+    assert_eq!(Lowp::new(2.0), Lowp::new(1.5) + Lowp::new(1.5));
+
+    // A smooth ramp over 1000 pixels collapses into 2^8 + 1 bands.
+    let bands = (0..1000)
+        .map(|i| Lowp::f_from_f64(i as f64 / 1000.0).to_f32().to_bits())
+        .collect::<std::collections::BTreeSet<_>>();
+    assert_eq!(257, bands.len());
+}
@@ -0,0 +1,7 @@
+#[macro_use]
+extern crate glsl_linalg;
+
+fn main() {
+    let _ = glsl_mat3!(vec4!(1.0), vec4!(2.0));
+    let _ = glsl_mat2!(1, 2, 3, 4, 5);
+}
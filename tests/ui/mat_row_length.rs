@@ -0,0 +1,7 @@
+#[macro_use]
+extern crate glsl_linalg;
+
+fn main() {
+    let v3 = vec3!(1.0, 2.0, 3.0);
+    let _ = mat2!(vec2!(1.0, 2.0); v3);
+}
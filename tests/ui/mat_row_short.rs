@@ -0,0 +1,6 @@
+#[macro_use]
+extern crate glsl_linalg;
+
+fn main() {
+    let _ = mat3!(1, 2, 3; 4, 5; 6, 7, 8);
+}
@@ -0,0 +1,7 @@
+#[macro_use]
+extern crate glsl_linalg;
+
+fn main() {
+    let _ = mat2!(1, 2; 3, 4, 5);
+    let _ = mat4!(1, 2, 3, 4, 5; 1, 2, 3, 4; 1, 2, 3, 4; 1, 2, 3, 4);
+}
@@ -0,0 +1,6 @@
+#[macro_use]
+extern crate glsl_linalg;
+
+fn main() {
+    let _ = vec2!(1.0f32, 2.0f64);
+}
@@ -0,0 +1,6 @@
+#[macro_use]
+extern crate glsl_linalg;
+
+fn main() {
+    let _ = vec4!(1.0, vec2!(2.0, 3.0));
+}
@@ -0,0 +1,6 @@
+#[macro_use]
+extern crate glsl_linalg;
+
+fn main() {
+    let _ = vec3!(vec2!(1.0, 2.0));
+}
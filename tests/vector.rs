@@ -1,7 +1,9 @@
 #[macro_use]
 extern crate glsl_linalg;
 
-use glsl_linalg::vector::{FloatVector, Vector};
+use glsl_linalg::vector::{Cross, FloatVector, Vector};
+use glsl_linalg::{V2, V3};
+use std::ops::{Add, Mul, Sub};
 
 #[test]
 fn v_mul_f() {
@@ -88,3 +90,183 @@ fn v_distance() {
 
     assert_eq!(5.0, v1.distance(&v2));
 }
+
+#[test]
+fn v_cross() {
+    let v1 = vec3!(1, 2, 3);
+    let v2 = vec3!(4, 5, 6);
+
+    assert_eq!(vec3!(-3, 6, -3), v1.cross(v2));
+    assert_eq!(vec3!(0, 0, 1), vec3!(1, 0, 0).cross(vec3!(0, 1, 0)));
+    assert_eq!(vec3!(0, 0, -3), vec2!(1, 2).cross(vec2!(3, 3)));
+}
+
+#[test]
+fn v_generic_size() {
+    let v1 = Vector([1, 2, 3, 4, 5]);
+    let v2 = Vector([5, 4, 3, 2, 1]);
+
+    assert_eq!(Vector([6; 5]), v1 + v2);
+    assert_eq!(35, v1.dot(v2));
+    assert_eq!(Vector([2, 4, 6, 8, 10]), 2 * v1);
+}
+
+#[test]
+fn v_assign_ops() {
+    let mut v = vec3!(1.0, 2.0, 3.0);
+
+    v += vec3!(1.0);
+    assert_eq!(vec3!(2.0, 3.0, 4.0), v);
+    v -= 1.0;
+    assert_eq!(vec3!(1.0, 2.0, 3.0), v);
+    v *= 2.0;
+    assert_eq!(vec3!(2.0, 4.0, 6.0), v);
+    v /= vec3!(2.0, 4.0, 3.0);
+    assert_eq!(vec3!(1.0, 1.0, 2.0), v);
+    v *= mat3!(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 1.0);
+    assert_eq!(vec3!(3.0, 3.0, 2.0), v);
+    v %= 2.0;
+    assert_eq!(vec3!(1.0, 1.0, 0.0), v);
+}
+
+#[test]
+fn v_neg() {
+    assert_eq!(vec3!(-1, 2, -3), -vec3!(1, -2, 3));
+}
+
+#[test]
+fn v_rem() {
+    assert_eq!(vec3!(1, 2, 0), vec3!(7, 8, 9) % 3);
+    assert_eq!(vec2!(1.5, 0.0), vec2!(5.5, 6.0) % vec2!(2.0, 3.0));
+}
+
+#[test]
+fn v_add_f() {
+    assert_eq!(vec4!(2.0, 3.0, 4.0, 5.0), vec4!(1.0, 2.0, 3.0, 4.0) + 1.0);
+    assert_eq!(vec2!(-1, 0), vec2!(1, 2) - 2);
+}
+
+fn lerp<V, F>(a: &V, b: &V, t: F) -> V
+where
+    for<'a> &'a V: Sub<&'a V, Output = V> + Mul<F, Output = V>,
+    for<'a> V: Add<&'a V, Output = V>,
+{
+    &(b - a) * t + a
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn v_ref_ops() {
+    let v1 = vec3!(1.0, 2.0, 3.0);
+    let v2 = vec3!(3.0, 2.0, 1.0);
+
+    assert_eq!(vec3!(4.0), &v1 + &v2);
+    assert_eq!(vec3!(-2.0, 0.0, 2.0), v1 - &v2);
+    assert_eq!(vec3!(3.0, 4.0, 3.0), &v1 * v2);
+    assert_eq!(vec3!(0.5, 1.0, 1.5), &v1 / 2.0);
+    assert_eq!(vec3!(-1.0, -2.0, -3.0), -&v1);
+    assert_eq!(vec3!(2.0), lerp(&v1, &v2, 0.5));
+
+    let m = mat3!(1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0);
+    assert_eq!(vec3!(1.0, 4.0, 9.0), &v1 * &m);
+
+    let mut v = v1;
+    v += &v2;
+    assert_eq!(vec3!(4.0), v);
+}
+
+#[test]
+fn v_sum_product() {
+    let points = [vec3!(1.0, 2.0, 3.0), vec3!(3.0, 2.0, 1.0), vec3!(2.0)];
+
+    assert_eq!(vec3!(6.0), points.iter().sum::<V3<f64>>());
+    assert_eq!(vec3!(2.0), points.iter().sum::<V3<f64>>() / 3.0);
+    assert_eq!(vec3!(6.0, 8.0, 6.0), points.iter().copied().product());
+    assert_eq!(vec2!(0), Vec::<V2<i32>>::new().into_iter().sum());
+    assert_eq!(vec2!(1), Vec::<V2<i32>>::new().into_iter().product());
+}
+
+#[test]
+fn v_iter() {
+    let mut v = vec4!(1, 2, 3, 4);
+
+    assert_eq!(10, v.iter().sum::<i32>());
+    for e in v.iter_mut() {
+        *e *= 2;
+    }
+    for e in &mut v {
+        *e += 1;
+    }
+    assert_eq!(
+        vec![3, 5, 7, 9],
+        (&v).into_iter().copied().collect::<Vec<_>>()
+    );
+    assert_eq!(vec![3, 5, 7, 9], v.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn v_try_from_iter() {
+    assert_eq!(Some(vec3!(0, 1, 2)), V3::try_from_iter(0..3));
+    assert_eq!(None, V3::try_from_iter(0..2));
+    assert_eq!(None, V3::try_from_iter(0..4));
+    assert_eq!(None, V3::try_from_iter(std::iter::repeat(1)));
+    assert_eq!(Some(vec3!(0, 1, 2)), V3::try_from_iter(exhaustible(3)));
+    assert_eq!(None, V3::try_from_iter(exhaustible(2)));
+}
+
+/// Yields `0..n`, then panics if it is polled again after returning `None`.
+fn exhaustible(n: i32) -> impl Iterator<Item = i32> {
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        assert!(i <= n, "polled after returning None");
+        i += 1;
+        if i <= n {
+            Some(i - 1)
+        } else {
+            None
+        }
+    })
+}
+
+#[test]
+fn v_map() {
+    let v = vec3!(1.5, -2.5, 3.0);
+
+    assert_eq!(vec3!(1, -2, 3), v.map(|e| e as i32));
+    assert_eq!(vec3!(1.5, 2.5, 3.0), v.map(f64::abs));
+    assert_eq!(
+        V3([true, false, true]),
+        v.zip_with(vec3!(1.0, 0.0, 2.0), |a, b| a > b)
+    );
+}
+
+#[test]
+fn v_fold() {
+    let v = vec4!(3, -1, 4, 2);
+
+    assert_eq!(8, v.fold(0, |sum, e| sum + e));
+    assert_eq!(-24, v.reduce(|a, b| a * b));
+    assert_eq!(-1, v.min_element());
+    assert_eq!(4, v.max_element());
+    assert_eq!(8, v.sum_elements());
+    assert_eq!(-24, v.product_elements());
+}
+
+#[test]
+fn v_const() {
+    const A: V3<f32> = vec3!(const 1.0, 2.0, 3.0);
+    const B: V3<f32> = V3::<f32>::splat(2.0).const_sub(V3::X);
+    const DOT: f32 = A.const_dot(B);
+    const Z: f32 = A.z();
+    const C: V3<i32> = V3::new([1i32, 2, 3])
+        .const_mul(vec3!(const 2))
+        .const_add(V3::Y)
+        .const_scale(2)
+        .const_neg();
+
+    assert_eq!(vec3!(1.0, 2.0, 2.0), B);
+    assert_eq!(A.dot(B), DOT);
+    assert_eq!(3.0, Z);
+    assert_eq!(-(vec3!(2, 4, 6) + V3::Y) * 2, C);
+    assert_eq!((1, 2), (V2([1, 2]).x(), V2([1, 2]).component(1)));
+}